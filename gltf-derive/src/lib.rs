@@ -9,7 +9,7 @@ extern crate proc_macro;
 use proc_macro::TokenStream;
 use syn::DeriveInput;
 
-#[proc_macro_derive(Validate)]
+#[proc_macro_derive(Validate, attributes(gltf))]
 pub fn derive_validate(input: TokenStream) -> TokenStream {
     expand(&syn::parse_macro_input!(input as DeriveInput)).into()
 }
@@ -38,6 +38,11 @@ fn expand(ast: &DeriveInput) -> proc_macro2::TokenStream {
             )
         })
         .collect();
+    let hook = validate_hook(ast).map(|hook| {
+        quote!(
+            #hook(self, _root, _path, _report)
+        )
+    });
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
     quote!(
         impl #impl_generics crate::validation::Validate
@@ -55,7 +60,29 @@ fn expand(ast: &DeriveInput) -> proc_macro2::TokenStream {
                 #(
                     #validations;
                 )*
+                #hook
             }
         }
     )
 }
+
+/// Parses `#[gltf(validate_hook = "path")]`, naming a function called after
+/// the fields are validated with the arguments of `Validate::validate`.
+fn validate_hook(ast: &DeriveInput) -> Option<syn::Path> {
+    use syn::{Lit, Meta, NestedMeta};
+
+    let attr = ast.attrs.iter().find(|attr| attr.path.is_ident("gltf"))?;
+    let hook = match attr.parse_meta() {
+        Ok(Meta::List(ref list)) if list.nested.len() == 1 => match list.nested[0] {
+            NestedMeta::Meta(Meta::NameValue(ref pair)) if pair.path.is_ident("validate_hook") => {
+                match pair.lit {
+                    Lit::Str(ref hook) => hook.parse().ok(),
+                    _ => None,
+                }
+            }
+            _ => None,
+        },
+        _ => None,
+    };
+    Some(hook.expect("expected `#[gltf(validate_hook = \"...\")]`"))
+}
@@ -105,7 +105,7 @@ pub struct Channel {
 }
 
 /// The index of the node and TRS property that an animation channel targets.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Target {
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -118,10 +118,22 @@ pub struct Target {
     pub extras: Extras,
 
     /// The index of the node to target.
-    pub node: Index<scene::Node>,
+    ///
+    /// Required unless the target is defined by an extension such as
+    /// `KHR_animation_pointer`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Index<scene::Node>>,
 
     /// The name of the node's property to modify or the 'weights' of the
     /// morph targets it instantiates.
+    ///
+    /// The `"pointer"` path of `KHR_animation_pointer` targets is not a
+    /// `Property` and is read as `Checked::Invalid`, which is written back
+    /// as `"pointer"`.
+    #[cfg_attr(
+        feature = "KHR_animation_pointer",
+        serde(serialize_with = "serialize_pointer_path")
+    )]
     pub path: Checked<Property>,
 }
 
@@ -157,12 +169,68 @@ impl Validate for Animation {
     {
         self.samplers
             .validate(root, || path().field("samplers"), report);
+        self.extensions
+            .validate(root, || path().field("extensions"), report);
         for (index, channel) in self.channels.iter().enumerate() {
             if channel.sampler.value() as usize >= self.samplers.len() {
                 let path = || path().field("channels").index(index).field("sampler");
                 report(&path, Error::IndexOutOfBounds);
             }
+            channel.target.validate(
+                root,
+                || path().field("channels").index(index).field("target"),
+                report,
+            );
+        }
+    }
+}
+
+impl Validate for Target {
+    fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+    where
+        P: Fn() -> Path,
+        R: FnMut(&dyn Fn() -> Path, Error),
+    {
+        self.extensions
+            .validate(root, || path().field("extensions"), report);
+        self.extras
+            .validate(root, || path().field("extras"), report);
+
+        #[cfg(feature = "KHR_animation_pointer")]
+        {
+            let pointer = self
+                .extensions
+                .as_ref()
+                .and_then(|extensions| extensions.khr_animation_pointer.as_ref());
+            if pointer.is_some() {
+                // The pointer replaces both the node and the property.
+                if self.node.is_some() {
+                    report(&|| path().field("node"), Error::Invalid);
+                }
+                if let Checked::Valid(_) = self.path {
+                    report(&|| path().field("path"), Error::Invalid);
+                }
+                return;
+            }
         }
+
+        if self.node.is_none() {
+            report(&|| path().field("node"), Error::Missing);
+        }
+        self.node.validate(root, || path().field("node"), report);
+        self.path.validate(root, || path().field("path"), report);
+    }
+}
+
+/// Writes the `"pointer"` path of `KHR_animation_pointer` targets.
+#[cfg(feature = "KHR_animation_pointer")]
+fn serialize_pointer_path<S>(path: &Checked<Property>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: ser::Serializer,
+{
+    match path {
+        Checked::Valid(property) => ser::Serialize::serialize(property, serializer),
+        Checked::Invalid => serializer.serialize_str("pointer"),
     }
 }
 
@@ -1,18 +1,353 @@
+use crate::validation::{Error, Validate};
+use crate::{Path, Root};
 use gltf_derive::Validate;
 use serde_derive::{Deserialize, Serialize};
 
 /// A keyframe animation.
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct Animation {}
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+pub struct Animation {
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[serde(
+        default,
+        rename = "KHR_xmp_json_ld",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub khr_xmp_json_ld: Option<crate::extensions::root::khr_xmp_json_ld::PacketReference>,
+
+    /// Unrecognized extension data, preserved as raw JSON.
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
 
 /// Targets an animation's sampler at a node's property.
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct Channel {}
+pub struct Channel {
+    /// Unrecognized extension data, preserved as raw JSON.
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
 
 /// The index of the node and TRS property that an animation channel targets.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Target {}
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Target {
+    #[cfg(feature = "KHR_animation_pointer")]
+    #[serde(
+        default,
+        rename = "KHR_animation_pointer",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub khr_animation_pointer: Option<khr_animation_pointer::KhrAnimationPointer>,
+
+    /// Unrecognized extension data, preserved as raw JSON.
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Validate for Target {
+    fn validate<P, R>(&self, _root: &Root, _path: P, _report: &mut R)
+    where
+        P: Fn() -> Path,
+        R: FnMut(&dyn Fn() -> Path, Error),
+    {
+        #[cfg(feature = "KHR_animation_pointer")]
+        self.khr_animation_pointer.validate(
+            _root,
+            || _path().field("KHR_animation_pointer"),
+            _report,
+        );
+    }
+}
 
 /// Defines a keyframe graph but not its target.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Sampler {}
+pub struct Sampler {
+    /// Unrecognized extension data, preserved as raw JSON.
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
+
+#[cfg(feature = "KHR_animation_pointer")]
+pub mod khr_animation_pointer {
+    use crate::validation::{Error, Validate};
+    use crate::{camera, material, mesh, scene, Index, Path, Root};
+    use serde_derive::{Deserialize, Serialize};
+    use std::borrow::Cow;
+
+    /// Names of the top-level glTF properties that a pointer may start with.
+    pub const VALID_ROOT_PROPERTIES: &[&str] = &[
+        "accessors",
+        "animations",
+        "asset",
+        "buffers",
+        "bufferViews",
+        "cameras",
+        "extensions",
+        "extras",
+        "images",
+        "materials",
+        "meshes",
+        "nodes",
+        "samplers",
+        "scene",
+        "scenes",
+        "skins",
+        "textures",
+    ];
+
+    /// Targets an animation channel at an arbitrary property of the asset.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    pub struct KhrAnimationPointer {
+        /// JSON pointer (RFC 6901) to the animated property.
+        pub pointer: String,
+    }
+
+    /// An animatable property of a `Node`.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum NodeProperty {
+        /// XYZ translation vector.
+        Translation,
+        /// XYZW rotation quaternion.
+        Rotation,
+        /// XYZ scale vector.
+        Scale,
+        /// Weights of the morph targets instantiated by the node.
+        Weights,
+    }
+
+    /// An animatable property of a `Material`.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum MaterialProperty {
+        /// `pbrMetallicRoughness.baseColorFactor`.
+        BaseColorFactor,
+        /// `pbrMetallicRoughness.metallicFactor`.
+        MetallicFactor,
+        /// `pbrMetallicRoughness.roughnessFactor`.
+        RoughnessFactor,
+        /// `emissiveFactor`.
+        EmissiveFactor,
+        /// `alphaCutoff`.
+        AlphaCutoff,
+        /// `normalTexture.scale`.
+        NormalTextureScale,
+        /// `occlusionTexture.strength`.
+        OcclusionTextureStrength,
+    }
+
+    /// An animatable property of a `Camera`.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum CameraProperty {
+        /// `perspective.aspectRatio`.
+        PerspectiveAspectRatio,
+        /// `perspective.yfov`.
+        PerspectiveYfov,
+        /// `perspective.znear`.
+        PerspectiveZnear,
+        /// `perspective.zfar`.
+        PerspectiveZfar,
+        /// `orthographic.xmag`.
+        OrthographicXmag,
+        /// `orthographic.ymag`.
+        OrthographicYmag,
+        /// `orthographic.znear`.
+        OrthographicZnear,
+        /// `orthographic.zfar`.
+        OrthographicZfar,
+    }
+
+    /// An animatable property of a `KHR_lights_punctual` light.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum LightProperty {
+        /// `color`.
+        Color,
+        /// `intensity`.
+        Intensity,
+        /// `range`.
+        Range,
+        /// `spot.innerConeAngle`.
+        SpotInnerConeAngle,
+        /// `spot.outerConeAngle`.
+        SpotOuterConeAngle,
+    }
+
+    /// A parsed animation pointer.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum Pointer<'a> {
+        /// A property of a node.
+        Node(Index<scene::Node>, NodeProperty),
+
+        /// The default morph target weights of a mesh.
+        MeshWeights(Index<mesh::Mesh>),
+
+        /// A property of a material.
+        Material(Index<material::Material>, MaterialProperty),
+
+        /// A property of a camera.
+        Camera(Index<camera::Camera>, CameraProperty),
+
+        /// A property of a `KHR_lights_punctual` light.
+        #[cfg(feature = "KHR_lights_punctual")]
+        Light(
+            Index<crate::extensions::scene::khr_lights_punctual::Light>,
+            LightProperty,
+        ),
+
+        /// A well-formed pointer to a property that is not modeled by this crate.
+        Other(&'a str),
+    }
+
+    impl<'a> Pointer<'a> {
+        /// Parses a JSON pointer.
+        ///
+        /// Returns `None` if the pointer is malformed.
+        pub fn parse(pointer: &'a str) -> Option<Self> {
+            use self::CameraProperty::*;
+            use self::MaterialProperty::*;
+
+            let segments = segments(pointer)?;
+            let segments = segments.iter().map(|s| s.as_ref()).collect::<Vec<&str>>();
+            let parsed = match segments.as_slice() {
+                ["nodes", i, property] => {
+                    let property = match *property {
+                        "translation" => NodeProperty::Translation,
+                        "rotation" => NodeProperty::Rotation,
+                        "scale" => NodeProperty::Scale,
+                        "weights" => NodeProperty::Weights,
+                        _ => return Some(Pointer::Other(pointer)),
+                    };
+                    array_index(i).map(|i| Pointer::Node(Index::new(i), property))
+                }
+                ["meshes", i, "weights"] => {
+                    array_index(i).map(|i| Pointer::MeshWeights(Index::new(i)))
+                }
+                ["materials", i, rest @ ..] => {
+                    let property = match rest {
+                        ["pbrMetallicRoughness", "baseColorFactor"] => BaseColorFactor,
+                        ["pbrMetallicRoughness", "metallicFactor"] => MetallicFactor,
+                        ["pbrMetallicRoughness", "roughnessFactor"] => RoughnessFactor,
+                        ["emissiveFactor"] => EmissiveFactor,
+                        ["alphaCutoff"] => AlphaCutoff,
+                        ["normalTexture", "scale"] => NormalTextureScale,
+                        ["occlusionTexture", "strength"] => OcclusionTextureStrength,
+                        _ => return Some(Pointer::Other(pointer)),
+                    };
+                    array_index(i).map(|i| Pointer::Material(Index::new(i), property))
+                }
+                ["cameras", i, projection, property] => {
+                    let property = match (*projection, *property) {
+                        ("perspective", "aspectRatio") => PerspectiveAspectRatio,
+                        ("perspective", "yfov") => PerspectiveYfov,
+                        ("perspective", "znear") => PerspectiveZnear,
+                        ("perspective", "zfar") => PerspectiveZfar,
+                        ("orthographic", "xmag") => OrthographicXmag,
+                        ("orthographic", "ymag") => OrthographicYmag,
+                        ("orthographic", "znear") => OrthographicZnear,
+                        ("orthographic", "zfar") => OrthographicZfar,
+                        _ => return Some(Pointer::Other(pointer)),
+                    };
+                    array_index(i).map(|i| Pointer::Camera(Index::new(i), property))
+                }
+                #[cfg(feature = "KHR_lights_punctual")]
+                ["extensions", "KHR_lights_punctual", "lights", i, rest @ ..] => {
+                    let property = match rest {
+                        ["color"] => LightProperty::Color,
+                        ["intensity"] => LightProperty::Intensity,
+                        ["range"] => LightProperty::Range,
+                        ["spot", "innerConeAngle"] => LightProperty::SpotInnerConeAngle,
+                        ["spot", "outerConeAngle"] => LightProperty::SpotOuterConeAngle,
+                        _ => return Some(Pointer::Other(pointer)),
+                    };
+                    array_index(i).map(|i| Pointer::Light(Index::new(i), property))
+                }
+                _ => None,
+            };
+            Some(parsed.unwrap_or(Pointer::Other(pointer)))
+        }
+    }
+
+    /// Splits a JSON pointer into its unescaped reference tokens.
+    fn segments(pointer: &str) -> Option<Vec<Cow<'_, str>>> {
+        let rest = pointer.strip_prefix('/')?;
+        rest.split('/')
+            .map(|token| {
+                if !token.contains('~') {
+                    return Some(Cow::Borrowed(token));
+                }
+                let mut unescaped = String::with_capacity(token.len());
+                let mut chars = token.chars();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '~' => match chars.next() {
+                            Some('0') => unescaped.push('~'),
+                            Some('1') => unescaped.push('/'),
+                            _ => return None,
+                        },
+                        c => unescaped.push(c),
+                    }
+                }
+                Some(Cow::Owned(unescaped))
+            })
+            .collect()
+    }
+
+    /// Parses an array index reference token, which may not have leading zeros.
+    fn array_index(token: &str) -> Option<u32> {
+        let digits = !token.is_empty() && token.bytes().all(|b| b.is_ascii_digit());
+        if digits && (token == "0" || !token.starts_with('0')) {
+            token.parse().ok()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of elements in a top-level array of the root object.
+    fn root_array_len(root: &Root, name: &str) -> Option<usize> {
+        Some(match name {
+            "accessors" => root.accessors.len(),
+            "animations" => root.animations.len(),
+            "buffers" => root.buffers.len(),
+            "bufferViews" => root.buffer_views.len(),
+            "cameras" => root.cameras.len(),
+            "images" => root.images.len(),
+            "materials" => root.materials.len(),
+            "meshes" => root.meshes.len(),
+            "nodes" => root.nodes.len(),
+            "samplers" => root.samplers.len(),
+            "scenes" => root.scenes.len(),
+            "skins" => root.skins.len(),
+            "textures" => root.textures.len(),
+            _ => return None,
+        })
+    }
+
+    impl Validate for KhrAnimationPointer {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where
+            P: Fn() -> Path,
+            R: FnMut(&dyn Fn() -> Path, Error),
+        {
+            let path = || path().field("pointer");
+            match Pointer::parse(&self.pointer) {
+                Some(Pointer::Node(index, _)) => index.validate(root, path, report),
+                Some(Pointer::MeshWeights(index)) => index.validate(root, path, report),
+                Some(Pointer::Material(index, _)) => index.validate(root, path, report),
+                Some(Pointer::Camera(index, _)) => index.validate(root, path, report),
+                #[cfg(feature = "KHR_lights_punctual")]
+                Some(Pointer::Light(index, _)) => index.validate(root, path, report),
+                Some(Pointer::Other(pointer)) => {
+                    let segments = segments(pointer).unwrap_or_default();
+                    let name = segments.first().map(|s| s.as_ref()).unwrap_or_default();
+                    if !VALID_ROOT_PROPERTIES.contains(&name) {
+                        report(&path, Error::Invalid);
+                    } else if let Some(len) = root_array_len(root, name) {
+                        match segments.get(1).and_then(|i| array_index(i)) {
+                            Some(i) if (i as usize) < len => {}
+                            Some(_) => report(&path, Error::IndexOutOfBounds),
+                            None => report(&path, Error::Invalid),
+                        }
+                    }
+                }
+                None => report(&path, Error::Invalid),
+            }
+        }
+    }
+}
@@ -3,4 +3,16 @@ use serde_derive::{Deserialize, Serialize};
 
 /// Metadata about the glTF asset.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Asset {}
+pub struct Asset {
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[serde(
+        default,
+        rename = "KHR_xmp_json_ld",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub khr_xmp_json_ld: Option<crate::extensions::root::khr_xmp_json_ld::PacketReference>,
+
+    /// Unrecognized extension data, preserved as raw JSON.
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
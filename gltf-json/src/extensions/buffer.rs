@@ -3,8 +3,16 @@ use serde_derive::{Deserialize, Serialize};
 
 /// A buffer points to binary data representing geometry, animations, or skins.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Buffer {}
+pub struct Buffer {
+    /// Unrecognized extension data, preserved as raw JSON.
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
 
 /// A view into a buffer generally representing a subset of the buffer.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct View {}
+pub struct View {
+    /// Unrecognized extension data, preserved as raw JSON.
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
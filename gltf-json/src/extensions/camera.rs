@@ -6,12 +6,24 @@ use serde_derive::{Deserialize, Serialize};
 /// A node can reference a camera to apply a transform to place the camera in the
 /// scene.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Camera {}
+pub struct Camera {
+    /// Unrecognized extension data, preserved as raw JSON.
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
 
 /// Values for an orthographic camera.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Orthographic {}
+pub struct Orthographic {
+    /// Unrecognized extension data, preserved as raw JSON.
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
 
 /// Values for a perspective camera.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Perspective {}
+pub struct Perspective {
+    /// Unrecognized extension data, preserved as raw JSON.
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
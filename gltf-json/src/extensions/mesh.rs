@@ -6,7 +6,19 @@ use serde_derive::{Deserialize, Serialize};
 /// A node can contain one or more meshes and its transform places the meshes in
 /// the scene.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Mesh {}
+pub struct Mesh {
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[serde(
+        default,
+        rename = "KHR_xmp_json_ld",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub khr_xmp_json_ld: Option<crate::extensions::root::khr_xmp_json_ld::PacketReference>,
+
+    /// Unrecognized extension data, preserved as raw JSON.
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
 
 /// Geometry to be rendered with the given material.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
@@ -18,6 +30,26 @@ pub struct Primitive {
         skip_serializing_if = "Option::is_none"
     )]
     pub khr_materials_variants: Option<KhrMaterialsVariants>,
+
+    #[cfg(feature = "EXT_mesh_features")]
+    #[serde(
+        default,
+        rename = "EXT_mesh_features",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ext_mesh_features: Option<ext_mesh_features::ExtMeshFeatures>,
+
+    #[cfg(feature = "EXT_structural_metadata")]
+    #[serde(
+        default,
+        rename = "EXT_structural_metadata",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ext_structural_metadata: Option<ext_structural_metadata::ExtStructuralMetadata>,
+
+    /// Unrecognized extension data, preserved as raw JSON.
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
 }
 
 #[cfg(feature = "KHR_materials_variants")]
@@ -32,3 +64,162 @@ pub struct Mapping {
     pub material: u32,
     pub variants: Vec<u32>,
 }
+
+#[cfg(feature = "EXT_mesh_features")]
+pub mod ext_mesh_features {
+    use crate::validation::{Error, Validate};
+    use crate::{texture, Extras, Index, Path, Root};
+    use gltf_derive::Validate;
+    use serde_derive::{Deserialize, Serialize};
+
+    /// Feature identifiers of a primitive.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    pub struct ExtMeshFeatures {
+        /// Sets of feature IDs, each stored in a vertex attribute, a texture,
+        /// or implicitly as the vertex index.
+        pub feature_ids: Vec<FeatureId>,
+    }
+
+    /// A set of feature IDs.
+    ///
+    /// When neither `attribute` nor `texture` is defined, the feature ID of
+    /// each vertex is its index.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct FeatureId {
+        /// The number of unique features in the set.
+        pub feature_count: u32,
+
+        /// A value indicating that no feature is associated with a vertex or
+        /// texel.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub null_feature_id: Option<u32>,
+
+        /// A label identifying the set of feature IDs.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub label: Option<String>,
+
+        /// The set index of the `_FEATURE_ID_n` vertex attribute.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub attribute: Option<u32>,
+
+        /// A texture containing feature IDs.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub texture: Option<FeatureIdTexture>,
+
+        /// The index of the property table containing per-feature metadata,
+        /// as defined by the `EXT_structural_metadata` extension.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub property_table: Option<u32>,
+
+        /// Extension specific data.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub extensions: Option<std::boxed::Box<serde_json::value::RawValue>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+        pub extras: Extras,
+    }
+
+    /// A texture containing feature IDs.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    pub struct FeatureIdTexture {
+        /// The index of the texture.
+        pub index: Index<texture::Texture>,
+
+        /// The set index of the texture's `TEXCOORD` attribute.
+        #[serde(default, rename = "texCoord")]
+        pub tex_coord: u32,
+
+        /// Texture channels containing the feature ID, packed from least to
+        /// most significant byte.
+        #[serde(default = "channels_default")]
+        pub channels: Vec<u32>,
+
+        /// Extension specific data.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub extensions: Option<std::boxed::Box<serde_json::value::RawValue>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+        pub extras: Extras,
+    }
+
+    fn channels_default() -> Vec<u32> {
+        vec![0]
+    }
+
+    impl Validate for FeatureId {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where
+            P: Fn() -> Path,
+            R: FnMut(&dyn Fn() -> Path, Error),
+        {
+            if self.feature_count == 0 {
+                report(&|| path().field("featureCount"), Error::Invalid);
+            }
+
+            #[cfg(feature = "EXT_structural_metadata")]
+            if let Some(property_table) = self.property_table {
+                let index =
+                    Index::<crate::extensions::root::ext_structural_metadata::PropertyTable>::new(
+                        property_table,
+                    );
+                index.validate(root, || path().field("propertyTable"), report);
+            }
+
+            self.texture
+                .validate(root, || path().field("texture"), report);
+            self.extensions
+                .validate(root, || path().field("extensions"), report);
+            self.extras
+                .validate(root, || path().field("extras"), report);
+        }
+    }
+
+    impl Validate for FeatureIdTexture {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where
+            P: Fn() -> Path,
+            R: FnMut(&dyn Fn() -> Path, Error),
+        {
+            // Feature IDs are at most 32 bits wide and packed into 8-bit channels.
+            if self.channels.is_empty() || self.channels.len() > 4 {
+                report(&|| path().field("channels"), Error::Invalid);
+            }
+
+            self.index.validate(root, || path().field("index"), report);
+            self.extensions
+                .validate(root, || path().field("extensions"), report);
+            self.extras
+                .validate(root, || path().field("extras"), report);
+        }
+    }
+}
+
+#[cfg(feature = "EXT_structural_metadata")]
+pub mod ext_structural_metadata {
+    use crate::extensions::root::ext_structural_metadata::{PropertyAttribute, PropertyTexture};
+    use crate::Index;
+    use gltf_derive::Validate;
+    use serde_derive::{Deserialize, Serialize};
+
+    /// Property textures and property attributes used by a primitive.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    pub struct ExtStructuralMetadata {
+        /// Indices of the property textures sampled by the primitive.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub property_textures: Vec<Index<PropertyTexture>>,
+
+        /// Indices of the property attributes stored in the primitive's
+        /// vertex attributes.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub property_attributes: Vec<Index<PropertyAttribute>>,
+    }
+}
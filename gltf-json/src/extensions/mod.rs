@@ -50,6 +50,20 @@ pub const ENABLED_EXTENSIONS: &[&str] = &[
     "KHR_materials_transmission",
     #[cfg(feature = "KHR_materials_ior")]
     "KHR_materials_ior",
+    #[cfg(feature = "KHR_animation_pointer")]
+    "KHR_animation_pointer",
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    "EXT_mesh_gpu_instancing",
+    #[cfg(feature = "MSFT_lod")]
+    "MSFT_lod",
+    #[cfg(feature = "EXT_lights_image_based")]
+    "EXT_lights_image_based",
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    "KHR_xmp_json_ld",
+    #[cfg(feature = "EXT_mesh_features")]
+    "EXT_mesh_features",
+    #[cfg(feature = "EXT_structural_metadata")]
+    "EXT_structural_metadata",
 ];
 
 /// Names of glTF 2.0 extensions supported by the library.
@@ -60,4 +74,11 @@ pub const SUPPORTED_EXTENSIONS: &[&str] = &[
     "KHR_texture_transform",
     "KHR_materials_transmission",
     "KHR_materials_ior",
+    "KHR_animation_pointer",
+    "EXT_mesh_gpu_instancing",
+    "MSFT_lod",
+    "EXT_lights_image_based",
+    "KHR_xmp_json_ld",
+    "EXT_mesh_features",
+    "EXT_structural_metadata",
 ];
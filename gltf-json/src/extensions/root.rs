@@ -19,6 +19,34 @@ pub struct Root {
         skip_serializing_if = "Option::is_none"
     )]
     pub khr_materials_variants: Option<KhrMaterialsVariants>,
+
+    #[cfg(feature = "EXT_lights_image_based")]
+    #[serde(
+        default,
+        rename = "EXT_lights_image_based",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ext_lights_image_based: Option<ExtLightsImageBased>,
+
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[serde(
+        default,
+        rename = "KHR_xmp_json_ld",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub khr_xmp_json_ld: Option<khr_xmp_json_ld::KhrXmpJsonLd>,
+
+    #[cfg(feature = "EXT_structural_metadata")]
+    #[serde(
+        default,
+        rename = "EXT_structural_metadata",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ext_structural_metadata: Option<ext_structural_metadata::ExtStructuralMetadata>,
+
+    /// Unrecognized extension data, preserved as raw JSON.
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
 }
 
 #[cfg(feature = "KHR_lights_punctual")]
@@ -28,6 +56,125 @@ pub struct KhrLightsPunctual {
     pub lights: Vec<crate::extensions::scene::khr_lights_punctual::Light>,
 }
 
+/// Reports `KHR_lights_punctual` on every object other than the root and
+/// nodes, which are the only objects that may carry the extension.
+///
+/// The extension is left in the unrecognized `others` of such objects, so
+/// this runs whether or not the root declares any lights.
+#[cfg(feature = "KHR_lights_punctual")]
+pub(crate) fn validate_light_references<R>(root: &crate::Root, report: &mut R)
+where
+    R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+{
+    use crate::Path;
+
+    macro_rules! check {
+        ($extensions:expr, $path:expr) => {
+            if matches!(
+                $extensions,
+                Some(ref extensions) if extensions.others.contains_key("KHR_lights_punctual")
+            ) {
+                report(
+                    &|| $path.field("extensions").field("khrLightsPunctual"),
+                    crate::validation::Error::Invalid,
+                );
+            }
+        };
+    }
+
+    for (i, accessor) in root.accessors.iter().enumerate() {
+        let path = || Path::new().field("accessors").index(i);
+        check!(accessor.extensions, path());
+        if let Some(ref sparse) = accessor.sparse {
+            check!(sparse.extensions, path().field("sparse"));
+            check!(
+                sparse.indices.extensions,
+                path().field("sparse").field("indices")
+            );
+            check!(
+                sparse.values.extensions,
+                path().field("sparse").field("values")
+            );
+        }
+    }
+    for (i, animation) in root.animations.iter().enumerate() {
+        let path = || Path::new().field("animations").index(i);
+        check!(animation.extensions, path());
+        for (j, channel) in animation.channels.iter().enumerate() {
+            let path = || path().field("channels").index(j);
+            check!(channel.extensions, path());
+            check!(channel.target.extensions, path().field("target"));
+        }
+        for (j, sampler) in animation.samplers.iter().enumerate() {
+            check!(sampler.extensions, path().field("samplers").index(j));
+        }
+    }
+    check!(root.asset.extensions, Path::new().field("asset"));
+    for (i, buffer) in root.buffers.iter().enumerate() {
+        check!(buffer.extensions, Path::new().field("buffers").index(i));
+    }
+    for (i, view) in root.buffer_views.iter().enumerate() {
+        check!(view.extensions, Path::new().field("bufferViews").index(i));
+    }
+    for (i, camera) in root.cameras.iter().enumerate() {
+        let path = || Path::new().field("cameras").index(i);
+        check!(camera.extensions, path());
+        if let Some(ref orthographic) = camera.orthographic {
+            check!(orthographic.extensions, path().field("orthographic"));
+        }
+        if let Some(ref perspective) = camera.perspective {
+            check!(perspective.extensions, path().field("perspective"));
+        }
+    }
+    for (i, image) in root.images.iter().enumerate() {
+        check!(image.extensions, Path::new().field("images").index(i));
+    }
+    for (i, material) in root.materials.iter().enumerate() {
+        let path = || Path::new().field("materials").index(i);
+        check!(material.extensions, path());
+        let pbr = &material.pbr_metallic_roughness;
+        let pbr_path = || path().field("pbrMetallicRoughness");
+        check!(pbr.extensions, pbr_path());
+        if let Some(ref info) = pbr.base_color_texture {
+            check!(info.extensions, pbr_path().field("baseColorTexture"));
+        }
+        if let Some(ref info) = pbr.metallic_roughness_texture {
+            check!(
+                info.extensions,
+                pbr_path().field("metallicRoughnessTexture")
+            );
+        }
+        if let Some(ref texture) = material.normal_texture {
+            check!(texture.extensions, path().field("normalTexture"));
+        }
+        if let Some(ref texture) = material.occlusion_texture {
+            check!(texture.extensions, path().field("occlusionTexture"));
+        }
+        if let Some(ref info) = material.emissive_texture {
+            check!(info.extensions, path().field("emissiveTexture"));
+        }
+    }
+    for (i, mesh) in root.meshes.iter().enumerate() {
+        let path = || Path::new().field("meshes").index(i);
+        check!(mesh.extensions, path());
+        for (j, primitive) in mesh.primitives.iter().enumerate() {
+            check!(primitive.extensions, path().field("primitives").index(j));
+        }
+    }
+    for (i, sampler) in root.samplers.iter().enumerate() {
+        check!(sampler.extensions, Path::new().field("samplers").index(i));
+    }
+    for (i, scene) in root.scenes.iter().enumerate() {
+        check!(scene.extensions, Path::new().field("scenes").index(i));
+    }
+    for (i, skin) in root.skins.iter().enumerate() {
+        check!(skin.extensions, Path::new().field("skins").index(i));
+    }
+    for (i, texture) in root.textures.iter().enumerate() {
+        check!(texture.extensions, Path::new().field("textures").index(i));
+    }
+}
+
 #[cfg(feature = "KHR_lights_punctual")]
 impl crate::root::Get<crate::extensions::scene::khr_lights_punctual::Light> for crate::Root {
     fn get(
@@ -66,3 +213,1017 @@ impl crate::root::Get<crate::extensions::scene::khr_materials_variants::Variant>
             .get(id.value())
     }
 }
+
+#[cfg(feature = "EXT_lights_image_based")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+pub struct ExtLightsImageBased {
+    /// Image based lights available to scenes.
+    pub lights: Vec<crate::extensions::scene::ext_lights_image_based::ImageBasedLight>,
+}
+
+#[cfg(feature = "EXT_lights_image_based")]
+impl crate::root::Get<crate::extensions::scene::ext_lights_image_based::ImageBasedLight>
+    for crate::Root
+{
+    fn get(
+        &self,
+        id: crate::Index<crate::extensions::scene::ext_lights_image_based::ImageBasedLight>,
+    ) -> Option<&crate::extensions::scene::ext_lights_image_based::ImageBasedLight> {
+        self.extensions
+            .as_ref()?
+            .ext_lights_image_based
+            .as_ref()?
+            .lights
+            .get(id.value())
+    }
+}
+
+#[cfg(feature = "KHR_xmp_json_ld")]
+pub mod khr_xmp_json_ld {
+    use crate::validation::Validate;
+    use crate::{Index, Root};
+    use gltf_derive::Validate;
+    use serde_derive::{Deserialize, Serialize};
+
+    /// XMP metadata packets of the asset.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    pub struct KhrXmpJsonLd {
+        /// Dictionaries of XMP metadata properties, serialized as JSON-LD.
+        pub packets: Vec<Packet>,
+    }
+
+    /// An XMP metadata packet serialized as a JSON-LD object.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct Packet(pub serde_json::Map<String, serde_json::Value>);
+
+    impl Validate for Packet {}
+
+    /// Associates an object with an XMP metadata packet.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    pub struct PacketReference {
+        /// The index of the packet in the root packet array.
+        pub packet: Index<Packet>,
+    }
+
+    impl crate::root::Get<Packet> for Root {
+        fn get(&self, id: Index<Packet>) -> Option<&Packet> {
+            self.extensions
+                .as_ref()?
+                .khr_xmp_json_ld
+                .as_ref()?
+                .packets
+                .get(id.value())
+        }
+    }
+}
+
+#[cfg(feature = "EXT_structural_metadata")]
+pub mod ext_structural_metadata {
+    use crate::validation::{Checked, Error, Validate};
+    use crate::{buffer, texture, Extras, Index, Path, Root, Value};
+    use gltf_derive::Validate;
+    use serde::{de, ser};
+    use serde_derive::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+    use std::fmt;
+
+    /// All valid property types.
+    pub const VALID_TYPES: &[&str] = &[
+        "SCALAR", "VEC2", "VEC3", "VEC4", "MAT2", "MAT3", "MAT4", "STRING", "BOOLEAN", "ENUM",
+    ];
+
+    /// All valid property component types.
+    pub const VALID_COMPONENT_TYPES: &[&str] = &[
+        "INT8", "UINT8", "INT16", "UINT16", "INT32", "UINT32", "INT64", "UINT64", "FLOAT32",
+        "FLOAT64",
+    ];
+
+    /// All valid array and string offset types.
+    pub const VALID_OFFSET_TYPES: &[&str] = &["UINT8", "UINT16", "UINT32", "UINT64"];
+
+    /// Structured metadata of the asset.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    pub struct ExtStructuralMetadata {
+        /// An embedded schema describing the classes and enums of the metadata.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub schema: Option<Schema>,
+
+        /// The URI of an external schema, used when `schema` is not embedded.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub schema_uri: Option<String>,
+
+        /// Property tables storing per-feature metadata in buffer views.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub property_tables: Vec<PropertyTable>,
+
+        /// Property textures storing metadata in texture channels.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub property_textures: Vec<PropertyTexture>,
+
+        /// Property attributes storing metadata in vertex attributes.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub property_attributes: Vec<PropertyAttribute>,
+
+        /// Extension specific data.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub extensions: Option<std::boxed::Box<serde_json::value::RawValue>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+        pub extras: Extras,
+    }
+
+    /// Classes and enums that describe the structure of the metadata.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    pub struct Schema {
+        /// Unique identifier of the schema.
+        pub id: String,
+
+        /// Optional user-defined name for this object.
+        #[cfg(feature = "names")]
+        #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
+        pub name: Option<String>,
+
+        /// The description of the schema.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+
+        /// Application specific version of the schema.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub version: Option<String>,
+
+        /// Classes keyed by their identifiers.
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        pub classes: BTreeMap<String, Class>,
+
+        /// Enums keyed by their identifiers.
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        pub enums: BTreeMap<String, Enum>,
+
+        /// Extension specific data.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub extensions: Option<std::boxed::Box<serde_json::value::RawValue>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+        pub extras: Extras,
+    }
+
+    /// A class containing a set of properties.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    pub struct Class {
+        /// Optional user-defined name for this object.
+        #[cfg(feature = "names")]
+        #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
+        pub name: Option<String>,
+
+        /// The description of the class.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+
+        /// Class properties keyed by their identifiers.
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        pub properties: BTreeMap<String, ClassProperty>,
+
+        /// Extension specific data.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub extensions: Option<std::boxed::Box<serde_json::value::RawValue>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+        pub extras: Extras,
+    }
+
+    /// A single property of a class.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct ClassProperty {
+        /// Optional user-defined name for this object.
+        #[cfg(feature = "names")]
+        #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
+        pub name: Option<String>,
+
+        /// The description of the property.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+
+        /// The element type of the property.
+        #[serde(rename = "type")]
+        pub type_: Checked<Type>,
+
+        /// The numeric component type, required for scalar, vector and matrix
+        /// properties.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub component_type: Option<Checked<ComponentType>>,
+
+        /// The identifier of the enum, required for enum properties.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub enum_type: Option<String>,
+
+        /// Whether the property is an array of elements.
+        #[serde(default)]
+        pub array: bool,
+
+        /// The number of elements of a fixed-length array. Arrays without a
+        /// count have a variable length.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub count: Option<u32>,
+
+        /// Whether integer components are normalized to `[0, 1]` or `[-1, 1]`.
+        #[serde(default)]
+        pub normalized: bool,
+
+        /// An offset added to the property values.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub offset: Option<Value>,
+
+        /// A factor multiplied with the property values.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub scale: Option<Value>,
+
+        /// The maximum property value, after the transforms are applied.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub max: Option<Value>,
+
+        /// The minimum property value, after the transforms are applied.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub min: Option<Value>,
+
+        /// Whether every property table, texture or attribute of the class must
+        /// provide the property.
+        #[serde(default)]
+        pub required: bool,
+
+        /// A sentinel value indicating missing data, before the transforms
+        /// are applied.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub no_data: Option<Value>,
+
+        /// The value used in place of missing data.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub default: Option<Value>,
+
+        /// An identifier describing how the property should be interpreted.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub semantic: Option<String>,
+
+        /// Extension specific data.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub extensions: Option<std::boxed::Box<serde_json::value::RawValue>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+        pub extras: Extras,
+    }
+
+    /// A set of named integer values.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Enum {
+        /// Optional user-defined name for this object.
+        #[cfg(feature = "names")]
+        #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
+        pub name: Option<String>,
+
+        /// The description of the enum.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+
+        /// The integer type used to store enum values.
+        #[serde(default = "value_type_default")]
+        pub value_type: Checked<ComponentType>,
+
+        /// The named values of the enum.
+        pub values: Vec<EnumValue>,
+
+        /// Extension specific data.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub extensions: Option<std::boxed::Box<serde_json::value::RawValue>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+        pub extras: Extras,
+    }
+
+    /// A named value of an enum.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    pub struct EnumValue {
+        /// The name of the enum value.
+        pub name: String,
+
+        /// The description of the enum value.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+
+        /// The integer value.
+        pub value: i64,
+
+        /// Extension specific data.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub extensions: Option<std::boxed::Box<serde_json::value::RawValue>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+        pub extras: Extras,
+    }
+
+    /// Per-feature property values of a class, stored in buffer views.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    pub struct PropertyTable {
+        /// Optional user-defined name for this object.
+        #[cfg(feature = "names")]
+        #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
+        pub name: Option<String>,
+
+        /// The identifier of the class describing the properties.
+        pub class: String,
+
+        /// The number of features, and so the number of rows of each property.
+        pub count: u32,
+
+        /// Property columns keyed by class property identifiers.
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        pub properties: BTreeMap<String, PropertyTableProperty>,
+
+        /// Extension specific data.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub extensions: Option<std::boxed::Box<serde_json::value::RawValue>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+        pub extras: Extras,
+    }
+
+    /// A column of a property table.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PropertyTableProperty {
+        /// The buffer view containing the property values.
+        pub values: Index<buffer::View>,
+
+        /// The buffer view containing byte offsets of variable-length arrays,
+        /// or bit offsets for boolean arrays. String arrays index the string
+        /// offsets buffer view in bytes.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub array_offsets: Option<Index<buffer::View>>,
+
+        /// The buffer view containing byte offsets of strings within the
+        /// values buffer view.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub string_offsets: Option<Index<buffer::View>>,
+
+        /// The type of the array offsets.
+        #[serde(default = "offset_type_default")]
+        pub array_offset_type: Checked<OffsetType>,
+
+        /// The type of the string offsets.
+        #[serde(default = "offset_type_default")]
+        pub string_offset_type: Checked<OffsetType>,
+
+        /// An offset added to the property values, overriding the class
+        /// property offset.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub offset: Option<Value>,
+
+        /// A factor multiplied with the property values, overriding the class
+        /// property scale.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub scale: Option<Value>,
+
+        /// The maximum property value, after the transforms are applied.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub max: Option<Value>,
+
+        /// The minimum property value, after the transforms are applied.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub min: Option<Value>,
+
+        /// Extension specific data.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub extensions: Option<std::boxed::Box<serde_json::value::RawValue>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+        pub extras: Extras,
+    }
+
+    /// Property values of a class, stored in texture channels.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    pub struct PropertyTexture {
+        /// Optional user-defined name for this object.
+        #[cfg(feature = "names")]
+        #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
+        pub name: Option<String>,
+
+        /// The identifier of the class describing the properties.
+        pub class: String,
+
+        /// Texture properties keyed by class property identifiers.
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        pub properties: BTreeMap<String, PropertyTextureProperty>,
+
+        /// Extension specific data.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub extensions: Option<std::boxed::Box<serde_json::value::RawValue>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+        pub extras: Extras,
+    }
+
+    /// A property stored in the channels of a texture.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    pub struct PropertyTextureProperty {
+        /// The index of the texture.
+        pub index: Index<texture::Texture>,
+
+        /// The set index of the texture's `TEXCOORD` attribute.
+        #[serde(default, rename = "texCoord")]
+        pub tex_coord: u32,
+
+        /// Texture channels containing the property value, packed from least
+        /// to most significant byte.
+        #[serde(default = "channels_default")]
+        pub channels: Vec<u32>,
+
+        /// An offset added to the property values, overriding the class
+        /// property offset.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub offset: Option<Value>,
+
+        /// A factor multiplied with the property values, overriding the class
+        /// property scale.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub scale: Option<Value>,
+
+        /// The maximum property value, after the transforms are applied.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub max: Option<Value>,
+
+        /// The minimum property value, after the transforms are applied.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub min: Option<Value>,
+
+        /// Extension specific data.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub extensions: Option<std::boxed::Box<serde_json::value::RawValue>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+        pub extras: Extras,
+    }
+
+    /// Property values of a class, stored in vertex attributes.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    pub struct PropertyAttribute {
+        /// Optional user-defined name for this object.
+        #[cfg(feature = "names")]
+        #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
+        pub name: Option<String>,
+
+        /// The identifier of the class describing the properties.
+        pub class: String,
+
+        /// Attribute properties keyed by class property identifiers.
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        pub properties: BTreeMap<String, PropertyAttributeProperty>,
+
+        /// Extension specific data.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub extensions: Option<std::boxed::Box<serde_json::value::RawValue>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+        pub extras: Extras,
+    }
+
+    /// A property stored in a vertex attribute.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    pub struct PropertyAttributeProperty {
+        /// The name of the vertex attribute, such as `_TEMPERATURE`.
+        pub attribute: String,
+
+        /// An offset added to the property values, overriding the class
+        /// property offset.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub offset: Option<Value>,
+
+        /// A factor multiplied with the property values, overriding the class
+        /// property scale.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub scale: Option<Value>,
+
+        /// The maximum property value, after the transforms are applied.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub max: Option<Value>,
+
+        /// The minimum property value, after the transforms are applied.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub min: Option<Value>,
+
+        /// Extension specific data.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub extensions: Option<std::boxed::Box<serde_json::value::RawValue>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+        pub extras: Extras,
+    }
+
+    /// The element type of a class property.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum Type {
+        /// A single numeric component.
+        Scalar,
+
+        /// A fixed-length vector with 2 numeric components.
+        Vec2,
+
+        /// A fixed-length vector with 3 numeric components.
+        Vec3,
+
+        /// A fixed-length vector with 4 numeric components.
+        Vec4,
+
+        /// A 2x2 matrix with numeric components stored in column-major order.
+        Mat2,
+
+        /// A 3x3 matrix with numeric components stored in column-major order.
+        Mat3,
+
+        /// A 4x4 matrix with numeric components stored in column-major order.
+        Mat4,
+
+        /// A sequence of UTF-8 encoded characters.
+        String,
+
+        /// A single bit.
+        Boolean,
+
+        /// An integer value mapped to a named enum value.
+        Enum,
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<Type> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<Type>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_TYPES)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    use self::Type::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "SCALAR" => Valid(Scalar),
+                        "VEC2" => Valid(Vec2),
+                        "VEC3" => Valid(Vec3),
+                        "VEC4" => Valid(Vec4),
+                        "MAT2" => Valid(Mat2),
+                        "MAT3" => Valid(Mat3),
+                        "MAT4" => Valid(Mat4),
+                        "STRING" => Valid(String),
+                        "BOOLEAN" => Valid(Boolean),
+                        "ENUM" => Valid(Enum),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for Type {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_str(match *self {
+                Type::Scalar => "SCALAR",
+                Type::Vec2 => "VEC2",
+                Type::Vec3 => "VEC3",
+                Type::Vec4 => "VEC4",
+                Type::Mat2 => "MAT2",
+                Type::Mat3 => "MAT3",
+                Type::Mat4 => "MAT4",
+                Type::String => "STRING",
+                Type::Boolean => "BOOLEAN",
+                Type::Enum => "ENUM",
+            })
+        }
+    }
+
+    /// The numeric type of property components and enum values.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum ComponentType {
+        /// Signed 8-bit integer.
+        I8,
+
+        /// Unsigned 8-bit integer.
+        U8,
+
+        /// Signed 16-bit integer.
+        I16,
+
+        /// Unsigned 16-bit integer.
+        U16,
+
+        /// Signed 32-bit integer.
+        I32,
+
+        /// Unsigned 32-bit integer.
+        U32,
+
+        /// Signed 64-bit integer.
+        I64,
+
+        /// Unsigned 64-bit integer.
+        U64,
+
+        /// 32-bit floating point number.
+        F32,
+
+        /// 64-bit floating point number.
+        F64,
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<ComponentType> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<ComponentType>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_COMPONENT_TYPES)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    use self::ComponentType::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "INT8" => Valid(I8),
+                        "UINT8" => Valid(U8),
+                        "INT16" => Valid(I16),
+                        "UINT16" => Valid(U16),
+                        "INT32" => Valid(I32),
+                        "UINT32" => Valid(U32),
+                        "INT64" => Valid(I64),
+                        "UINT64" => Valid(U64),
+                        "FLOAT32" => Valid(F32),
+                        "FLOAT64" => Valid(F64),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for ComponentType {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_str(match *self {
+                ComponentType::I8 => "INT8",
+                ComponentType::U8 => "UINT8",
+                ComponentType::I16 => "INT16",
+                ComponentType::U16 => "UINT16",
+                ComponentType::I32 => "INT32",
+                ComponentType::U32 => "UINT32",
+                ComponentType::I64 => "INT64",
+                ComponentType::U64 => "UINT64",
+                ComponentType::F32 => "FLOAT32",
+                ComponentType::F64 => "FLOAT64",
+            })
+        }
+    }
+
+    /// The integer type of array and string offsets.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum OffsetType {
+        /// Unsigned 8-bit offsets.
+        U8,
+
+        /// Unsigned 16-bit offsets.
+        U16,
+
+        /// Unsigned 32-bit offsets.
+        U32,
+
+        /// Unsigned 64-bit offsets.
+        U64,
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<OffsetType> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<OffsetType>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_OFFSET_TYPES)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    use self::OffsetType::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "UINT8" => Valid(U8),
+                        "UINT16" => Valid(U16),
+                        "UINT32" => Valid(U32),
+                        "UINT64" => Valid(U64),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for OffsetType {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.serialize_str(match *self {
+                OffsetType::U8 => "UINT8",
+                OffsetType::U16 => "UINT16",
+                OffsetType::U32 => "UINT32",
+                OffsetType::U64 => "UINT64",
+            })
+        }
+    }
+
+    impl Type {
+        /// Returns the number of numeric components of an element, or `1` for
+        /// non-numeric types.
+        pub fn multiplicity(&self) -> usize {
+            match *self {
+                Type::Vec2 => 2,
+                Type::Vec3 => 3,
+                Type::Vec4 | Type::Mat2 => 4,
+                Type::Mat3 => 9,
+                Type::Mat4 => 16,
+                _ => 1,
+            }
+        }
+    }
+
+    impl ComponentType {
+        /// Returns the size of a component in bytes.
+        pub fn size(&self) -> usize {
+            match *self {
+                ComponentType::I8 | ComponentType::U8 => 1,
+                ComponentType::I16 | ComponentType::U16 => 2,
+                ComponentType::I32 | ComponentType::U32 | ComponentType::F32 => 4,
+                ComponentType::I64 | ComponentType::U64 | ComponentType::F64 => 8,
+            }
+        }
+    }
+
+    impl OffsetType {
+        /// Returns the size of an offset in bytes.
+        pub fn size(&self) -> usize {
+            match *self {
+                OffsetType::U8 => 1,
+                OffsetType::U16 => 2,
+                OffsetType::U32 => 4,
+                OffsetType::U64 => 8,
+            }
+        }
+    }
+
+    fn value_type_default() -> Checked<ComponentType> {
+        Checked::Valid(ComponentType::U16)
+    }
+
+    fn offset_type_default() -> Checked<OffsetType> {
+        Checked::Valid(OffsetType::U32)
+    }
+
+    fn channels_default() -> Vec<u32> {
+        vec![0]
+    }
+
+    /// Returns the embedded schema of the asset.
+    fn schema(root: &Root) -> Option<&Schema> {
+        root.extensions
+            .as_ref()?
+            .ext_structural_metadata
+            .as_ref()?
+            .schema
+            .as_ref()
+    }
+
+    /// Reports properties that are not declared by the class. Assets with an
+    /// external schema are not checked.
+    fn validate_class<'a, P, R>(
+        root: &'a Root,
+        class: &str,
+        properties: impl Iterator<Item = &'a String>,
+        path: P,
+        report: &mut R,
+    ) -> Option<&'a Class>
+    where
+        P: Fn() -> Path,
+        R: FnMut(&dyn Fn() -> Path, Error),
+    {
+        let schema = schema(root)?;
+        let class = match schema.classes.get(class) {
+            Some(class) => class,
+            None => {
+                report(&|| path().field("class"), Error::Invalid);
+                return None;
+            }
+        };
+        for id in properties {
+            if !class.properties.contains_key(id) {
+                report(&|| path().field("properties").key(id), Error::Invalid);
+            }
+        }
+        Some(class)
+    }
+
+    impl Validate for ClassProperty {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where
+            P: Fn() -> Path,
+            R: FnMut(&dyn Fn() -> Path, Error),
+        {
+            match self.type_ {
+                Checked::Valid(Type::Enum) => match self.enum_type.as_ref() {
+                    Some(id) => {
+                        // Enums of an external schema cannot be checked.
+                        let declared = schema(root).map(|schema| schema.enums.contains_key(id));
+                        if declared == Some(false) {
+                            report(&|| path().field("enumType"), Error::Invalid);
+                        }
+                    }
+                    None => report(&|| path().field("enumType"), Error::Missing),
+                },
+                Checked::Valid(Type::String) | Checked::Valid(Type::Boolean) => {}
+                _ => {
+                    if self.component_type.is_none() {
+                        report(&|| path().field("componentType"), Error::Missing);
+                    }
+                }
+            }
+            if self.count.is_some() && !self.array {
+                report(&|| path().field("count"), Error::Invalid);
+            }
+
+            self.type_.validate(root, || path().field("type"), report);
+            self.component_type
+                .validate(root, || path().field("componentType"), report);
+            self.extensions
+                .validate(root, || path().field("extensions"), report);
+            self.extras
+                .validate(root, || path().field("extras"), report);
+        }
+    }
+
+    impl Validate for PropertyTable {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where
+            P: Fn() -> Path,
+            R: FnMut(&dyn Fn() -> Path, Error),
+        {
+            let class = validate_class(root, &self.class, self.properties.keys(), &path, report);
+            for (id, property) in &self.properties {
+                let path = || path().field("properties").key(id);
+                if let Some(class_property) = class.and_then(|class| class.properties.get(id)) {
+                    if class_property.type_ == Checked::Valid(Type::String)
+                        && property.string_offsets.is_none()
+                    {
+                        report(&|| path().field("stringOffsets"), Error::Missing);
+                    }
+                    if class_property.array
+                        && class_property.count.is_none()
+                        && property.array_offsets.is_none()
+                    {
+                        report(&|| path().field("arrayOffsets"), Error::Missing);
+                    }
+                }
+                property.validate(root, path, report);
+            }
+
+            self.extensions
+                .validate(root, || path().field("extensions"), report);
+            self.extras
+                .validate(root, || path().field("extras"), report);
+        }
+    }
+
+    impl Validate for PropertyTexture {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where
+            P: Fn() -> Path,
+            R: FnMut(&dyn Fn() -> Path, Error),
+        {
+            validate_class(root, &self.class, self.properties.keys(), &path, report);
+            self.properties
+                .validate(root, || path().field("properties"), report);
+            self.extensions
+                .validate(root, || path().field("extensions"), report);
+            self.extras
+                .validate(root, || path().field("extras"), report);
+        }
+    }
+
+    impl Validate for PropertyAttribute {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where
+            P: Fn() -> Path,
+            R: FnMut(&dyn Fn() -> Path, Error),
+        {
+            validate_class(root, &self.class, self.properties.keys(), &path, report);
+            self.properties
+                .validate(root, || path().field("properties"), report);
+            self.extensions
+                .validate(root, || path().field("extensions"), report);
+            self.extras
+                .validate(root, || path().field("extras"), report);
+        }
+    }
+
+    impl crate::root::Get<PropertyTable> for Root {
+        fn get(&self, id: Index<PropertyTable>) -> Option<&PropertyTable> {
+            self.extensions
+                .as_ref()?
+                .ext_structural_metadata
+                .as_ref()?
+                .property_tables
+                .get(id.value())
+        }
+    }
+
+    impl crate::root::Get<PropertyTexture> for Root {
+        fn get(&self, id: Index<PropertyTexture>) -> Option<&PropertyTexture> {
+            self.extensions
+                .as_ref()?
+                .ext_structural_metadata
+                .as_ref()?
+                .property_textures
+                .get(id.value())
+        }
+    }
+
+    impl crate::root::Get<PropertyAttribute> for Root {
+        fn get(&self, id: Index<PropertyAttribute>) -> Option<&PropertyAttribute> {
+            self.extensions
+                .as_ref()?
+                .ext_structural_metadata
+                .as_ref()?
+                .property_attributes
+                .get(id.value())
+        }
+    }
+}
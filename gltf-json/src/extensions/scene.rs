@@ -20,6 +20,30 @@ pub struct Node {
         skip_serializing_if = "Option::is_none"
     )]
     pub khr_lights_punctual: Option<khr_lights_punctual::KhrLightsPunctual>,
+
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    #[serde(
+        default,
+        rename = "EXT_mesh_gpu_instancing",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ext_mesh_gpu_instancing: Option<ext_mesh_gpu_instancing::ExtMeshGpuInstancing>,
+
+    #[cfg(feature = "MSFT_lod")]
+    #[serde(default, rename = "MSFT_lod", skip_serializing_if = "Option::is_none")]
+    pub msft_lod: Option<msft_lod::MsftLod>,
+
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[serde(
+        default,
+        rename = "KHR_xmp_json_ld",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub khr_xmp_json_ld: Option<crate::extensions::root::khr_xmp_json_ld::PacketReference>,
+
+    /// Unrecognized extension data, preserved as raw JSON.
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
 }
 
 #[cfg(feature = "KHR_lights_punctual")]
@@ -122,6 +146,16 @@ pub mod khr_lights_punctual {
                 }
             }
 
+            if self.intensity < 0.0 {
+                report(&|| path().field("intensity"), Error::Invalid);
+            }
+            if let Some(range) = self.range {
+                if range <= 0.0 {
+                    report(&|| path().field("range"), Error::Invalid);
+                }
+            }
+
+            self.spot.validate(root, || path().field("spot"), report);
             self.type_.validate(root, || path().field("type"), report);
             self.extensions
                 .validate(root, || path().field("extensions"), report);
@@ -139,7 +173,7 @@ pub mod khr_lights_punctual {
     }
 
     /// Spot light parameters.
-    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    #[derive(Clone, Debug, Default, Deserialize, Serialize)]
     #[serde(rename_all = "camelCase")]
     pub struct Spot {
         /// Angle in radians from centre of spotlight where falloff begins.
@@ -155,6 +189,22 @@ pub mod khr_lights_punctual {
         std::f32::consts::FRAC_PI_4
     }
 
+    impl Validate for Spot {
+        fn validate<P, R>(&self, _: &Root, path: P, report: &mut R)
+        where
+            P: Fn() -> Path,
+            R: FnMut(&dyn Fn() -> Path, Error),
+        {
+            // The cone angles must satisfy `0 <= inner < outer <= PI / 2`.
+            if self.inner_cone_angle < 0.0 || self.inner_cone_angle >= self.outer_cone_angle {
+                report(&|| path().field("innerConeAngle"), Error::Invalid);
+            }
+            if self.outer_cone_angle > std::f32::consts::FRAC_PI_2 {
+                report(&|| path().field("outerConeAngle"), Error::Invalid);
+            }
+        }
+    }
+
     impl<'de> de::Deserialize<'de> for Checked<Type> {
         fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where
@@ -200,6 +250,229 @@ pub mod khr_lights_punctual {
     }
 }
 
+#[cfg(feature = "EXT_mesh_gpu_instancing")]
+pub mod ext_mesh_gpu_instancing {
+    use crate::accessor::{self, ComponentType, Type};
+    use crate::validation::{Checked, Error, Validate};
+    use crate::{Index, Path, Root};
+    use serde_derive::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+
+    /// Per-instance attributes used to draw the node's mesh many times.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize)]
+    pub struct ExtMeshGpuInstancing {
+        /// Maps instance attribute names to accessors containing one element
+        /// per instance.
+        ///
+        /// Valid names are `TRANSLATION`, `ROTATION`, `SCALE`, and
+        /// application specific names beginning with an underscore.
+        pub attributes: BTreeMap<String, Index<accessor::Accessor>>,
+    }
+
+    impl Validate for ExtMeshGpuInstancing {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where
+            P: Fn() -> Path,
+            R: FnMut(&dyn Fn() -> Path, Error),
+        {
+            self.attributes
+                .validate(root, || path().field("attributes"), report);
+
+            let mut count = None;
+            for (name, index) in &self.attributes {
+                let path = || path().field("attributes").key(name);
+                let expected_type = match name.as_str() {
+                    "TRANSLATION" | "SCALE" => Some(Type::Vec3),
+                    "ROTATION" => Some(Type::Vec4),
+                    _ if name.starts_with('_') => None,
+                    _ => {
+                        report(&path, Error::Invalid);
+                        None
+                    }
+                };
+                if let Some(accessor) = root.get(*index) {
+                    if let (Some(ty), Checked::Valid(actual)) = (expected_type, &accessor.type_) {
+                        if ty != *actual {
+                            report(&path, Error::Invalid);
+                        }
+                    }
+                    if let (Some(_), Checked::Valid(component_type)) =
+                        (expected_type, &accessor.component_type)
+                    {
+                        if !valid_format(root, name, component_type.0, accessor.normalized) {
+                            report(&path, Error::Invalid);
+                        }
+                    }
+                    // All attribute accessors must have the same count.
+                    match count {
+                        None => count = Some(accessor.count),
+                        Some(count) if count != accessor.count => report(&path, Error::Invalid),
+                        _ => {}
+                    }
+                }
+            }
+
+            // Instances draw the mesh of the node owning the extension, so
+            // that node must have one. The owner is found by identity, as
+            // the extension does not know which node it belongs to.
+            for (index, node) in root.nodes.iter().enumerate() {
+                let instancing = node
+                    .extensions
+                    .as_ref()
+                    .and_then(|extensions| extensions.ext_mesh_gpu_instancing.as_ref());
+                let owned = matches!(instancing, Some(ext) if std::ptr::eq(ext, self));
+                if owned && node.mesh.is_none() {
+                    report(
+                        &|| Path::new().field("nodes").index(index).field("mesh"),
+                        Error::Missing,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Returns whether an accessor with the given component type may store the
+    /// named instance attribute.
+    ///
+    /// Rotations may be normalized signed integers.  Translations and scales
+    /// must be floats unless `KHR_mesh_quantization` is used, which also
+    /// permits signed integers, normalized or not.
+    fn valid_format(
+        root: &Root,
+        name: &str,
+        component_type: ComponentType,
+        normalized: bool,
+    ) -> bool {
+        let signed = matches!(component_type, ComponentType::I8 | ComponentType::I16);
+        match (name, component_type) {
+            (_, ComponentType::F32) => !normalized,
+            ("ROTATION", _) => signed && normalized,
+            _ => {
+                signed
+                    && root
+                        .extensions_used
+                        .iter()
+                        .any(|used| used == "KHR_mesh_quantization")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "EXT_lights_image_based")]
+pub mod ext_lights_image_based {
+    use crate::validation::{Error, Validate};
+    use crate::{image, Extras, Index, Path, Root};
+    use gltf_derive::Validate;
+    use serde_derive::{Deserialize, Serialize};
+
+    /// The number of spherical harmonic coefficients used for irradiance.
+    pub const IRRADIANCE_COEFFICIENT_COUNT: usize = 9;
+
+    /// The number of faces of a cube map.
+    pub const CUBE_FACE_COUNT: usize = 6;
+
+    /// Assigns an image-based light to a scene.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    pub struct ExtLightsImageBased {
+        /// The index of the image-based light that lights the scene.
+        pub light: Index<ImageBasedLight>,
+    }
+
+    /// An environment light defined by prefiltered specular cube maps and
+    /// irradiance spherical harmonics.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct ImageBasedLight {
+        /// Extension specific data.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub extensions: Option<std::boxed::Box<serde_json::value::RawValue>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+        pub extras: Extras,
+
+        /// Brightness multiplier for the environment.
+        #[serde(default = "intensity_default")]
+        pub intensity: f32,
+
+        /// Declares the nine spherical harmonic coefficients for the
+        /// irradiance of the environment, as linear RGB triples.
+        pub irradiance_coefficients: Vec<[f32; 3]>,
+
+        /// Optional user-defined name for this object.
+        #[cfg(feature = "names")]
+        #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
+        pub name: Option<String>,
+
+        /// XYZW quaternion rotating the environment.
+        #[serde(default = "rotation_default")]
+        pub rotation: [f32; 4],
+
+        /// The largest dimension, in pixels, of the first specular mip level.
+        pub specular_image_size: u32,
+
+        /// Prefiltered specular cube maps, one per mip level from largest to
+        /// smallest. Each level lists the images for the +X, -X, +Y, -Y, +Z
+        /// and -Z faces.
+        pub specular_images: Vec<Vec<Index<image::Image>>>,
+    }
+
+    impl Validate for ImageBasedLight {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where
+            P: Fn() -> Path,
+            R: FnMut(&dyn Fn() -> Path, Error),
+        {
+            if self.irradiance_coefficients.len() != IRRADIANCE_COEFFICIENT_COUNT {
+                report(&|| path().field("irradianceCoefficients"), Error::Invalid);
+            }
+            if self.specular_images.is_empty() {
+                report(&|| path().field("specularImages"), Error::Missing);
+            }
+            for (mip, faces) in self.specular_images.iter().enumerate() {
+                if faces.len() != CUBE_FACE_COUNT {
+                    report(
+                        &|| path().field("specularImages").index(mip),
+                        Error::Invalid,
+                    );
+                }
+            }
+
+            self.extensions
+                .validate(root, || path().field("extensions"), report);
+            self.extras
+                .validate(root, || path().field("extras"), report);
+            self.specular_images
+                .validate(root, || path().field("specularImages"), report);
+        }
+    }
+
+    fn intensity_default() -> f32 {
+        1.0
+    }
+
+    fn rotation_default() -> [f32; 4] {
+        [0.0, 0.0, 0.0, 1.0]
+    }
+}
+
+#[cfg(feature = "MSFT_lod")]
+pub mod msft_lod {
+    use crate::{scene, Index};
+    use gltf_derive::Validate;
+    use serde_derive::{Deserialize, Serialize};
+
+    /// Lower levels of detail that may replace a node.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    pub struct MsftLod {
+        /// Nodes to use as levels of detail, ordered from highest to lowest
+        /// detail. The node owning the extension is the highest level.
+        pub ids: Vec<Index<scene::Node>>,
+    }
+}
+
 #[cfg(feature = "KHR_materials_variants")]
 pub mod khr_materials_variants {
     use crate::validation::{Error, Validate};
@@ -224,4 +497,24 @@ pub mod khr_materials_variants {
 
 /// The root `Node`s of a scene.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Scene {}
+pub struct Scene {
+    #[cfg(feature = "EXT_lights_image_based")]
+    #[serde(
+        default,
+        rename = "EXT_lights_image_based",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ext_lights_image_based: Option<ext_lights_image_based::ExtLightsImageBased>,
+
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[serde(
+        default,
+        rename = "KHR_xmp_json_ld",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub khr_xmp_json_ld: Option<crate::extensions::root::khr_xmp_json_ld::PacketReference>,
+
+    /// Unrecognized extension data, preserved as raw JSON.
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
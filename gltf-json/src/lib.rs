@@ -84,6 +84,8 @@ pub use self::root::Root;
 #[doc(inline)]
 pub use serde_json::Error;
 #[doc(inline)]
+pub use serde_json::Map;
+#[doc(inline)]
 pub use serde_json::Value;
 
 /// Re-exports of `serde_json` deserialization functions.
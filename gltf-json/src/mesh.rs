@@ -210,7 +210,10 @@ pub struct MorphTarget {
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Ord, PartialOrd)]
 pub enum Semantic {
     /// Extra attribute name.
-    #[cfg(feature = "extras")]
+    ///
+    /// Without the `extras` feature, only the `_FEATURE_ID_n` attributes of
+    /// `EXT_mesh_features` are recognized.
+    #[cfg(any(feature = "extras", feature = "EXT_mesh_features"))]
     Extras(String),
 
     /// XYZ vertex positions.
@@ -311,6 +314,8 @@ impl Semantic {
             "TANGENT" => Valid(Tangents),
             #[cfg(feature = "extras")]
             _ if s.starts_with('_') => Valid(Extras(s[1..].to_string())),
+            #[cfg(all(feature = "EXT_mesh_features", not(feature = "extras")))]
+            _ if s.starts_with("_FEATURE_ID_") => Valid(Extras(s[1..].to_string())),
             _ if s.starts_with("COLOR_") => match s["COLOR_".len()..].parse() {
                 Ok(set) => Valid(Colors(set)),
                 Err(_) => Invalid,
@@ -352,7 +357,7 @@ impl ToString for Semantic {
             TexCoords(set) => format!("TEXCOORD_{}", set),
             Joints(set) => format!("JOINTS_{}", set),
             Weights(set) => format!("WEIGHTS_{}", set),
-            #[cfg(feature = "extras")]
+            #[cfg(any(feature = "extras", feature = "EXT_mesh_features"))]
             Extras(ref name) => format!("_{}", name),
         }
     }
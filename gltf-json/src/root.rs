@@ -24,6 +24,7 @@ pub struct Index<T>(u32, marker::PhantomData<fn() -> T>);
 
 /// The root object of a glTF 2.0 asset.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[gltf(validate_hook = "root_validate_hook")]
 pub struct Root {
     /// An array of accessors.
     #[serde(default)]
@@ -118,6 +119,16 @@ pub struct Root {
     pub textures: Vec<Texture>,
 }
 
+/// Validates rules that span several objects of the asset.
+fn root_validate_hook<P, R>(_root: &Root, _: &Root, _path: P, _report: &mut R)
+where
+    P: Fn() -> Path,
+    R: FnMut(&dyn Fn() -> Path, validation::Error),
+{
+    #[cfg(feature = "KHR_lights_punctual")]
+    extensions::root::validate_light_references(_root, _report);
+}
+
 impl Root {
     /// Returns a single item from the root object.
     pub fn get<T>(&self, index: Index<T>) -> Option<&T>
@@ -150,6 +150,8 @@ impl Validate for std::boxed::Box<serde_json::value::RawValue> {
     }
 }
 
+impl Validate for serde_json::Map<String, serde_json::Value> {}
+
 impl std::error::Error for Error {}
 
 impl std::fmt::Display for Error {
@@ -170,6 +172,7 @@ impl std::fmt::Display for Error {
 impl Validate for bool {}
 impl Validate for u32 {}
 impl Validate for i32 {}
+impl Validate for i64 {}
 impl Validate for f32 {}
 impl Validate for [f32; 3] {}
 impl Validate for [f32; 4] {}
@@ -0,0 +1,58 @@
+use serde_json::{json, Value};
+
+#[test]
+fn test_unknown_extensions_roundtrip() {
+    let original = json!({
+        "asset": {
+            "version": "2.0",
+            "extensions": { "EXAMPLE_asset": { "generator": [1, 2, 3] } }
+        },
+        "extensionsUsed": ["EXAMPLE_root", "EXAMPLE_node", "EXAMPLE_material"],
+        "extensions": {
+            "EXAMPLE_root": { "nested": { "value": true } }
+        },
+        "materials": [
+            {
+                "extensions": { "EXAMPLE_material": { "factor": 0.5 } },
+                "normalTexture": {
+                    "index": 0,
+                    "extensions": { "EXAMPLE_texture_info": {} }
+                }
+            }
+        ],
+        "nodes": [
+            {
+                "extensions": {
+                    "EXAMPLE_node": { "list": ["a", "b"] },
+                    "EXAMPLE_other": null
+                }
+            }
+        ],
+        "textures": [{ "source": 0 }],
+        "images": [{ "uri": "image.png" }]
+    });
+
+    let root: gltf_json::Root = serde_json::from_value(original.clone()).unwrap();
+    let node_extensions = root.nodes[0].extensions.as_ref().unwrap();
+    assert_eq!(node_extensions.others.len(), 2);
+    assert_eq!(
+        node_extensions.others["EXAMPLE_node"],
+        json!({ "list": ["a", "b"] })
+    );
+
+    let written: Value = serde_json::to_value(&root).unwrap();
+    assert_eq!(written["extensions"], original["extensions"]);
+    assert_eq!(
+        written["asset"]["extensions"],
+        original["asset"]["extensions"]
+    );
+    assert_eq!(written["nodes"], original["nodes"]);
+    assert_eq!(
+        written["materials"][0]["extensions"],
+        original["materials"][0]["extensions"]
+    );
+    assert_eq!(
+        written["materials"][0]["normalTexture"]["extensions"],
+        original["materials"][0]["normalTexture"]["extensions"]
+    );
+}
@@ -44,3 +44,176 @@ fn test_non_sparse_accessor_without_buffer_view_validate() {
         [(Path("accessors[0].bufferView".into()), Error::Missing)]
     );
 }
+
+#[cfg(feature = "KHR_animation_pointer")]
+#[test]
+fn test_animation_pointer_validate() {
+    let json = import_json("tests/animation_pointer_invalid.gltf");
+    let mut errs = vec![];
+    json.validate(&json, gltf_json::Path::new, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    let target =
+        |i: usize, field: &str| Path(format!("animations[0].channels[{}].target.{}", i, field));
+    let pointer = "extensions.KHR_animation_pointer.pointer";
+    assert_eq!(
+        errs,
+        [
+            (target(0, pointer), Error::IndexOutOfBounds),
+            (target(1, pointer), Error::Invalid),
+            (target(2, pointer), Error::Invalid),
+            (target(3, pointer), Error::IndexOutOfBounds),
+            (target(4, "node"), Error::Missing),
+            (target(4, "path"), Error::Invalid),
+            (target(5, "node"), Error::Invalid),
+            (target(6, "node"), Error::Missing),
+            (target(8, "path"), Error::Invalid),
+        ]
+    );
+}
+
+#[cfg(feature = "EXT_mesh_gpu_instancing")]
+#[test]
+fn test_mesh_gpu_instancing_validate() {
+    let json = import_json("tests/mesh_gpu_instancing_invalid.gltf");
+    let mut errs = vec![];
+    json.validate(&json, gltf_json::Path::new, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    let attribute = |node: usize, name: &str| {
+        Path(format!(
+            "nodes[{}].extensions.extMeshGpuInstancing.attributes[\"{}\"]",
+            node, name
+        ))
+    };
+    assert_eq!(
+        errs,
+        [
+            (attribute(0, "COLOR"), Error::Invalid),
+            (attribute(0, "SCALE"), Error::Invalid),
+            (Path("nodes[0].mesh".into()), Error::Missing),
+            (attribute(1, "ROTATION"), Error::Invalid),
+            (attribute(1, "TRANSLATION"), Error::Invalid),
+        ]
+    );
+}
+
+#[cfg(feature = "KHR_xmp_json_ld")]
+#[test]
+fn test_xmp_json_ld_validate() {
+    let json = import_json("tests/xmp_json_ld_invalid.gltf");
+    let mut errs = vec![];
+    json.validate(&json, gltf_json::Path::new, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    assert_eq!(
+        errs,
+        [(
+            Path("animations[0].extensions.khrXmpJsonLd.packet".into()),
+            Error::IndexOutOfBounds
+        )]
+    );
+}
+
+#[cfg(all(feature = "EXT_mesh_features", feature = "EXT_structural_metadata"))]
+#[test]
+fn test_structural_metadata_validate() {
+    let json = import_json("tests/structural_metadata_invalid.gltf");
+    let mut errs = vec![];
+    json.validate(&json, gltf_json::Path::new, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    let tree = "extensions.extStructuralMetadata.schema.classes[\"tree\"].properties";
+    let table = "extensions.extStructuralMetadata.propertyTables";
+    let feature_id = "meshes[0].primitives[0].extensions.extMeshFeatures.featureIds[0]";
+    assert_eq!(
+        errs,
+        [
+            (
+                Path(format!("{}[\"height\"].componentType", tree)),
+                Error::Missing
+            ),
+            (Path(format!("{}[\"kind\"].enumType", tree)), Error::Invalid),
+            (
+                Path(format!("{}[0].properties[\"age\"]", table)),
+                Error::Invalid
+            ),
+            (
+                Path(format!(
+                    "{}[0].properties[\"species\"].stringOffsets",
+                    table
+                )),
+                Error::Missing
+            ),
+            (Path(format!("{}[1].class", table)), Error::Invalid),
+            (Path(format!("{}.featureCount", feature_id)), Error::Invalid),
+            (
+                Path(format!("{}.propertyTable", feature_id)),
+                Error::IndexOutOfBounds
+            ),
+        ]
+    );
+}
+
+#[cfg(feature = "KHR_lights_punctual")]
+#[test]
+fn test_lights_punctual_validate() {
+    let json = import_json("tests/lights_punctual_invalid.gltf");
+    let mut errs = vec![];
+    json.validate(&json, gltf_json::Path::new, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    let lights = "extensions.khrLightsPunctual.lights";
+    assert_eq!(
+        errs,
+        [
+            (Path(format!("{}[0].intensity", lights)), Error::Invalid),
+            (Path(format!("{}[0].range", lights)), Error::Invalid),
+            (
+                Path(format!("{}[1].spot.innerConeAngle", lights)),
+                Error::Invalid
+            ),
+            (
+                Path(format!("{}[2].spot.innerConeAngle", lights)),
+                Error::Invalid
+            ),
+            (
+                Path(format!("{}[2].spot.outerConeAngle", lights)),
+                Error::Invalid
+            ),
+            (
+                Path("nodes[1].extensions.khrLightsPunctual.light".into()),
+                Error::IndexOutOfBounds
+            ),
+            (
+                Path("meshes[0].extensions.khrLightsPunctual".into()),
+                Error::Invalid
+            ),
+            (
+                Path("scenes[0].extensions.khrLightsPunctual".into()),
+                Error::Invalid
+            ),
+        ]
+    );
+
+    // Misplaced references are reported even without any root lights.
+    let json = import_json("tests/lights_punctual_misplaced.gltf");
+    let mut errs = vec![];
+    json.validate(&json, gltf_json::Path::new, &mut |path, err| {
+        errs.push((path(), err))
+    });
+    let misplaced = |object: &str| {
+        (
+            Path(format!("{}.extensions.khrLightsPunctual", object)),
+            Error::Invalid,
+        )
+    };
+    assert_eq!(
+        errs,
+        [
+            misplaced("asset"),
+            misplaced("materials[0].normalTexture"),
+            misplaced("samplers[0]"),
+        ]
+    );
+}
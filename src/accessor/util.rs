@@ -429,3 +429,55 @@ impl<'a, T: Item> Iterator for ItemIter<'a, T> {
         (hint, Some(hint))
     }
 }
+
+/// Reads the components of every element of an accessor, in order.
+///
+/// Matrices are read without the column padding required for some
+/// component types.
+#[cfg(any(feature = "extras", feature = "KHR_animation_pointer"))]
+pub(crate) fn components<'a, 's, T, F>(
+    accessor: accessor::Accessor<'a>,
+    get_buffer_data: F,
+) -> Option<Vec<T>>
+where
+    T: Item + Copy,
+    F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+{
+    use accessor::Dimensions;
+
+    let len = accessor.count() * accessor.dimensions().multiplicity();
+    let mut components = Vec::with_capacity(len);
+    match accessor.dimensions() {
+        Dimensions::Scalar => components.extend(Iter::<T>::new(accessor, get_buffer_data)?),
+        Dimensions::Vec2 => {
+            for element in Iter::<[T; 2]>::new(accessor, get_buffer_data)? {
+                components.extend_from_slice(&element);
+            }
+        }
+        Dimensions::Vec3 => {
+            for element in Iter::<[T; 3]>::new(accessor, get_buffer_data)? {
+                components.extend_from_slice(&element);
+            }
+        }
+        Dimensions::Vec4 | Dimensions::Mat2 => {
+            for element in Iter::<[T; 4]>::new(accessor, get_buffer_data)? {
+                components.extend_from_slice(&element);
+            }
+        }
+        Dimensions::Mat3 => {
+            for element in Iter::<[[T; 3]; 3]>::new(accessor, get_buffer_data)? {
+                for column in &element {
+                    components.extend_from_slice(column);
+                }
+            }
+        }
+        Dimensions::Mat4 => {
+            for element in Iter::<[[T; 4]; 4]>::new(accessor, get_buffer_data)? {
+                for column in &element {
+                    components.extend_from_slice(column);
+                }
+            }
+        }
+    }
+    Some(components)
+}
@@ -3,6 +3,9 @@ use crate::{accessor, scene, Document};
 #[cfg(feature = "utils")]
 use crate::Buffer;
 
+#[cfg(feature = "KHR_animation_pointer")]
+use crate::khr_animation_pointer::{NodeProperty, Pointer};
+
 pub use json::animation::{Interpolation, Property};
 
 /// Iterators.
@@ -84,6 +87,15 @@ impl<'a> Animation<'a> {
         &self.json.extras
     }
 
+    /// Returns the XMP metadata packet of this animation as defined by the
+    /// `KHR_xmp_json_ld` extension.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_xmp_json_ld")))]
+    pub fn xmp_packet(&self) -> Option<crate::khr_xmp_json_ld::Packet<'a>> {
+        self.document
+            .xmp_packet(self.json.extensions.as_ref()?.khr_xmp_json_ld.as_ref())
+    }
+
     /// Returns an `Iterator` over the animation channels.
     ///
     /// Each channel targets an animation's sampler at a node's property.
@@ -100,6 +112,60 @@ impl<'a> Animation<'a> {
         self.json.name.as_deref()
     }
 
+    /// Returns the duration of the animation, which is the latest keyframe
+    /// time of any of its samplers.
+    ///
+    /// The keyframe times are taken from the `max` bounds of the input
+    /// accessors, which are required for animation inputs. Returns `0.0` for
+    /// animations without bounded inputs.
+    pub fn duration(&self) -> f32 {
+        self.samplers()
+            .filter_map(|sampler| {
+                let max = sampler.input().max()?;
+                max.as_array()?.first()?.as_f64()
+            })
+            .fold(0.0, |duration: f64, max| duration.max(max)) as f32
+    }
+
+    /// Samples every channel of the animation at `time`, returning the local
+    /// transforms and morph weights of the targeted nodes.
+    ///
+    /// Properties that are not animated keep the rest values of their node.
+    /// Channels whose keyframes cannot be read and `KHR_animation_pointer`
+    /// channels are ignored.
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn sample<'s, F>(&self, time: f32, get_buffer_data: F) -> util::Pose<'a>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        use util::sampling::Value;
+
+        let mut pose = util::Pose {
+            document: self.document,
+            nodes: Vec::new(),
+        };
+        for channel in self.channels() {
+            let node = match channel.target().node() {
+                Some(node) => node,
+                None => continue,
+            };
+            let value = match channel.reader(get_buffer_data.clone()).sample(time) {
+                Some(value) => value,
+                None => continue,
+            };
+            let node_pose = pose.entry(node);
+            match value {
+                Value::Translation(translation) => node_pose.translation = translation,
+                Value::Rotation(rotation) => node_pose.rotation = rotation,
+                Value::Scale(scale) => node_pose.scale = scale,
+                Value::MorphTargetWeights(weights) => node_pose.weights = Some(weights),
+                Value::Components(_) => {}
+            }
+        }
+        pose
+    }
+
     /// Returns an `Iterator` over the animation samplers.
     ///
     /// Each sampler combines input and output accessors with an
@@ -134,6 +200,37 @@ impl<'a> Channel<'a> {
         Target::new(self.anim.clone(), &self.json.target)
     }
 
+    /// Returns the animated object and property.
+    ///
+    /// Targets defined by `KHR_animation_pointer` are resolved where the
+    /// pointer addresses a known property; otherwise the raw pointer is
+    /// returned as `Pointer::Other`. Node TRS and morph target weight targets
+    /// are reported as `Pointer::Node`. Returns `None` if the target is
+    /// invalid.
+    #[cfg(feature = "KHR_animation_pointer")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_animation_pointer")))]
+    pub fn pointer(&self) -> Option<Pointer<'a>> {
+        use json::validation::Checked;
+
+        let target = self.target();
+        if let Some(pointer) = target.pointer() {
+            return Some(Pointer::new(self.anim.document, pointer));
+        }
+        let property = match self.json.target.path {
+            Checked::Valid(Property::Translation) => NodeProperty::Translation,
+            Checked::Valid(Property::Rotation) => NodeProperty::Rotation,
+            Checked::Valid(Property::Scale) => NodeProperty::Scale,
+            Checked::Valid(Property::MorphTargetWeights) => NodeProperty::Weights,
+            Checked::Invalid => return None,
+        };
+        let node = self
+            .anim
+            .document
+            .nodes()
+            .nth(self.json.target.node?.value())?;
+        Some(Pointer::Node(node, property))
+    }
+
     /// Constructs an animation channel reader.
     #[cfg(feature = "utils")]
     #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
@@ -170,18 +267,36 @@ impl<'a> Target<'a> {
     }
 
     /// Returns the target node.
-    pub fn node(&self) -> scene::Node<'a> {
-        self.anim
-            .document
-            .nodes()
-            .nth(self.json.node.value())
-            .unwrap()
+    ///
+    /// Returns `None` if the target is defined by an extension such as
+    /// `KHR_animation_pointer`, which has no node.
+    pub fn node(&self) -> Option<scene::Node<'a>> {
+        self.anim.document.nodes().nth(self.json.node?.value())
     }
 
     /// Returns the node's property to modify or the 'weights' of the morph
     /// targets it instantiates.
-    pub fn property(&self) -> Property {
-        self.json.path.unwrap()
+    ///
+    /// Returns `None` if the target is defined by an extension such as
+    /// `KHR_animation_pointer`, which has no `Property`.
+    pub fn property(&self) -> Option<Property> {
+        match self.json.path {
+            json::validation::Checked::Valid(property) => Some(property),
+            json::validation::Checked::Invalid => None,
+        }
+    }
+
+    /// Returns the JSON pointer to the animated property if the target is
+    /// defined by `KHR_animation_pointer`.
+    #[cfg(feature = "KHR_animation_pointer")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_animation_pointer")))]
+    pub fn pointer(&self) -> Option<&'a str> {
+        self.json
+            .extensions
+            .as_ref()?
+            .khr_animation_pointer
+            .as_ref()
+            .map(|pointer| pointer.pointer.as_str())
     }
 }
 
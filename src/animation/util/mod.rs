@@ -4,6 +4,15 @@ pub mod rotations;
 /// Casting iterator adapters for morph target weights.
 pub mod morph_target_weights;
 
+/// Keyframe evaluation.
+pub mod sampling;
+
+mod optimize;
+mod pose;
+
+pub use self::optimize::rewrite_keyframes;
+pub use self::pose::{NodePose, Pose};
+
 use crate::accessor;
 
 use crate::animation::Channel;
@@ -58,6 +67,24 @@ pub enum MorphTargetWeights<'a> {
     F32(accessor::Iter<'a, f32>),
 }
 
+/// `KHR_animation_pointer` output values, shaped by the output accessor type.
+///
+/// Normalized integer outputs are converted to `f32` when read, so the
+/// values are decoded up front rather than while iterating.
+#[cfg(feature = "KHR_animation_pointer")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_animation_pointer")))]
+#[derive(Clone, Debug)]
+pub enum PointerValues {
+    /// Scalar values of type `f32`.
+    Scalar(std::vec::IntoIter<f32>),
+    /// Two component vectors of type `[f32; 2]`.
+    Vec2(std::vec::IntoIter<[f32; 2]>),
+    /// Three component vectors of type `[f32; 3]`.
+    Vec3(std::vec::IntoIter<[f32; 3]>),
+    /// Four component vectors of type `[f32; 4]`.
+    Vec4(std::vec::IntoIter<[f32; 4]>),
+}
+
 /// Animation output sampler values.
 pub enum ReadOutputs<'a> {
     /// XYZ translations of type `[f32; 3]`.
@@ -145,12 +172,89 @@ where
         accessor::Iter::new(self.channel.sampler().input(), self.get_buffer_data.clone())
     }
 
+    /// Reads the keyframes of a channel, normalizing quantized outputs.
+    ///
+    /// The number of components of each output is given by the animated
+    /// property: three for translations and scales, four for rotations and
+    /// the number of morph targets of the mesh for weights. Other properties
+    /// addressed by `KHR_animation_pointer` use the dimensions of the output
+    /// accessor.
+    ///
+    /// Returns `None` if the inputs or outputs cannot be read, their counts
+    /// do not match, or the inputs do not strictly increase.
+    pub fn read_keyframes(&self) -> Option<sampling::Keyframes> {
+        let inputs = self.read_inputs()?.collect();
+        let sampler = self.channel.sampler();
+
+        #[cfg(feature = "KHR_animation_pointer")]
+        if self.channel.target().pointer().is_some() {
+            use crate::animation::Property;
+            use crate::khr_animation_pointer::{NodeProperty, Pointer};
+
+            let (property, width) = match self.channel.pointer()? {
+                Pointer::Node(node, property) => {
+                    let property = match property {
+                        NodeProperty::Translation => Property::Translation,
+                        NodeProperty::Rotation => Property::Rotation,
+                        NodeProperty::Scale => Property::Scale,
+                        NodeProperty::Weights => Property::MorphTargetWeights,
+                    };
+                    (Some(property), property_width(property, &node)?)
+                }
+                _ => (None, sampler.output().dimensions().multiplicity()),
+            };
+            let outputs = self.read_pointer_components()?;
+            return sampling::Keyframes::new(
+                property,
+                sampler.interpolation(),
+                inputs,
+                outputs,
+                width,
+            );
+        }
+
+        let mut outputs = Vec::new();
+        match self.read_outputs()? {
+            ReadOutputs::Translations(iter) => iter.for_each(|x| outputs.extend_from_slice(&x)),
+            ReadOutputs::Rotations(iter) => {
+                iter.into_f32().for_each(|x| outputs.extend_from_slice(&x))
+            }
+            ReadOutputs::Scales(iter) => iter.for_each(|x| outputs.extend_from_slice(&x)),
+            ReadOutputs::MorphTargetWeights(iter) => outputs.extend(iter.into_f32()),
+        }
+        let target = self.channel.target();
+        let property = target.property()?;
+        sampling::Keyframes::new(
+            Some(property),
+            sampler.interpolation(),
+            inputs,
+            outputs,
+            property_width(property, &target.node()?)?,
+        )
+    }
+
+    /// Evaluates the channel at `time`, interpolating between keyframes.
+    ///
+    /// This reads every keyframe of the channel; use `read_keyframes` to
+    /// sample the same channel repeatedly.
+    pub fn sample(&self, time: f32) -> Option<sampling::Value> {
+        Some(self.read_keyframes()?.sample(time))
+    }
+
     /// Visits the output samples of a channel.
+    ///
+    /// Returns `None` for `KHR_animation_pointer` targets, whose outputs are
+    /// read by `read_pointer_outputs`.
     pub fn read_outputs(&self) -> Option<ReadOutputs<'s>> {
         use crate::animation::Property;
         use accessor::{DataType, Iter};
+        use json::validation::Checked;
         let output = self.channel.sampler().output();
-        match self.channel.target().property() {
+        let property = match self.channel.json.target.path {
+            Checked::Valid(property) => property,
+            Checked::Invalid => return None,
+        };
+        match property {
             Property::Translation => {
                 Iter::new(output, self.get_buffer_data.clone()).map(ReadOutputs::Translations)
             }
@@ -185,4 +289,87 @@ where
             },
         }
     }
+
+    /// Visits the output values of a `KHR_animation_pointer` channel.
+    ///
+    /// Integer outputs are converted to `f32` and must be normalized. Returns
+    /// `None` for other integer outputs, matrix outputs and channels that do
+    /// not target a pointer.
+    #[cfg(feature = "KHR_animation_pointer")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_animation_pointer")))]
+    pub fn read_pointer_outputs(&self) -> Option<PointerValues> {
+        use accessor::Dimensions;
+
+        self.channel.target().pointer()?;
+        let components = self.read_pointer_components()?;
+        Some(match self.channel.sampler().output().dimensions() {
+            Dimensions::Scalar => PointerValues::Scalar(components.into_iter()),
+            Dimensions::Vec2 => PointerValues::Vec2(
+                components
+                    .chunks_exact(2)
+                    .map(|c| [c[0], c[1]])
+                    .collect::<Vec<_>>()
+                    .into_iter(),
+            ),
+            Dimensions::Vec3 => PointerValues::Vec3(
+                components
+                    .chunks_exact(3)
+                    .map(|c| [c[0], c[1], c[2]])
+                    .collect::<Vec<_>>()
+                    .into_iter(),
+            ),
+            Dimensions::Vec4 => PointerValues::Vec4(
+                components
+                    .chunks_exact(4)
+                    .map(|c| [c[0], c[1], c[2], c[3]])
+                    .collect::<Vec<_>>()
+                    .into_iter(),
+            ),
+            _ => return None,
+        })
+    }
+
+    /// Reads the flattened output components of a `KHR_animation_pointer`
+    /// channel as `f32`, returning `None` for integer components that are
+    /// not normalized.
+    #[cfg(feature = "KHR_animation_pointer")]
+    fn read_pointer_components(&self) -> Option<Vec<f32>> {
+        use accessor::util::components;
+        use accessor::DataType;
+
+        let output = self.channel.sampler().output();
+        let normalized = output.normalized();
+        let get_buffer_data = self.get_buffer_data.clone();
+        Some(match output.data_type() {
+            DataType::F32 => components::<f32, _>(output, get_buffer_data)?,
+            DataType::I8 if normalized => normalize(components::<i8, _>(output, get_buffer_data)?),
+            DataType::U8 if normalized => normalize(components::<u8, _>(output, get_buffer_data)?),
+            DataType::I16 if normalized => {
+                normalize(components::<i16, _>(output, get_buffer_data)?)
+            }
+            DataType::U16 if normalized => {
+                normalize(components::<u16, _>(output, get_buffer_data)?)
+            }
+            _ => return None,
+        })
+    }
+}
+
+/// Converts normalized integer components to `f32`.
+#[cfg(feature = "KHR_animation_pointer")]
+fn normalize<T: crate::Normalize<f32>>(components: Vec<T>) -> Vec<f32> {
+    components.into_iter().map(|x| x.normalize()).collect()
+}
+
+/// Returns the number of components of each output element of a channel
+/// animating `property` of `node`.
+fn property_width(property: crate::animation::Property, node: &crate::Node) -> Option<usize> {
+    use crate::animation::Property;
+    match property {
+        Property::Translation | Property::Scale => Some(3),
+        Property::Rotation => Some(4),
+        Property::MorphTargetWeights => {
+            Some(node.mesh()?.primitives().next()?.morph_targets().len())
+        }
+    }
 }
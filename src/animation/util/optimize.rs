@@ -0,0 +1,348 @@
+use super::sampling::Keyframes;
+use crate::animation::{Channel, Interpolation, Property};
+use crate::{Buffer, Document};
+use json::validation::Checked::Valid;
+
+impl Keyframes {
+    /// Resamples the keyframes at a fixed `rate` in keyframes per second,
+    /// from the first to the last keyframe time.
+    ///
+    /// The last keyframe time is always kept, so the final interval may be
+    /// shorter than `1.0 / rate`. Cubic splines are resampled with linear
+    /// interpolation, while step interpolation is preserved. Returns the
+    /// keyframes unchanged if `rate` is not a positive number.
+    pub fn resample(&self, rate: f32) -> Keyframes {
+        if !(rate > 0.0 && rate.is_finite()) {
+            return self.clone();
+        }
+        let start = self.inputs[0];
+        let end = self.inputs[self.inputs.len() - 1];
+        // Intervals shorter than a thousandth of a frame are merged into
+        // the last keyframe to avoid near-duplicate times.
+        let intervals = ((end - start) * rate - 1e-3).ceil().max(0.0) as usize;
+        let mut inputs = (0..intervals)
+            .map(|i| start + i as f32 / rate)
+            .collect::<Vec<_>>();
+        inputs.push(end);
+
+        let outputs = inputs
+            .iter()
+            .flat_map(|time| self.sample_components(*time))
+            .collect();
+        let interpolation = match self.interpolation {
+            Interpolation::Step => Interpolation::Step,
+            _ => Interpolation::Linear,
+        };
+        Keyframes {
+            property: self.property,
+            interpolation,
+            inputs,
+            outputs,
+            width: self.width,
+        }
+    }
+
+    /// Removes keyframes that can be reconstructed by interpolating their
+    /// neighbours within `tolerance`.
+    ///
+    /// The tolerance is a distance for translations, an angle in radians for
+    /// rotations, a ratio for scales (`0.01` allows a 1% difference) and an
+    /// absolute difference for weights and other values. A constant channel
+    /// is reduced to a single keyframe. Cubic spline keyframes are returned
+    /// unchanged.
+    pub fn reduce(&self, tolerance: f32) -> Keyframes {
+        if self.interpolation == Interpolation::CubicSpline {
+            return self.clone();
+        }
+
+        let last = self.inputs.len() - 1;
+        let mut kept = vec![0];
+        let mut anchor = 0;
+        for candidate in 1..last {
+            let next = candidate + 1;
+            let duration = self.inputs[next] - self.inputs[anchor];
+            let removable = (anchor + 1..=candidate).all(|index| {
+                let t = if duration > 0.0 {
+                    (self.inputs[index] - self.inputs[anchor]) / duration
+                } else {
+                    0.0
+                };
+                let error = self.error(&self.interpolate(anchor, next, t), self.value(index));
+                error <= tolerance
+            });
+            if !removable {
+                kept.push(candidate);
+                anchor = candidate;
+            }
+        }
+        let is_constant =
+            kept.len() == 1 && self.error(self.value(0), self.value(last)) <= tolerance;
+        if last > 0 && !is_constant {
+            kept.push(last);
+        }
+
+        Keyframes {
+            property: self.property,
+            interpolation: self.interpolation,
+            inputs: kept.iter().map(|index| self.inputs[*index]).collect(),
+            outputs: kept
+                .iter()
+                .flat_map(|index| self.value(*index).iter().copied())
+                .collect(),
+            width: self.width,
+        }
+    }
+
+    /// Converts the keyframes to linear interpolation.
+    ///
+    /// Step keyframes are preceded by a copy of the previous value at the
+    /// closest earlier representable time, which reproduces the steps
+    /// exactly. Cubic splines are subdivided until the linear keyframes stay
+    /// within `tolerance` of the spline, measured as in `reduce`. Linear
+    /// keyframes are returned unchanged.
+    pub fn to_linear(&self, tolerance: f32) -> Keyframes {
+        let mut linear = Keyframes {
+            property: self.property,
+            interpolation: Interpolation::Linear,
+            inputs: vec![self.inputs[0]],
+            outputs: self.value(0).to_vec(),
+            width: self.width,
+        };
+        match self.interpolation {
+            Interpolation::Linear => return self.clone(),
+            Interpolation::Step => {
+                for index in 1..self.inputs.len() {
+                    let time = self.inputs[index];
+                    let before = next_down(time);
+                    if before > self.inputs[index - 1] {
+                        linear.inputs.push(before);
+                        linear.outputs.extend_from_slice(self.value(index - 1));
+                    }
+                    linear.inputs.push(time);
+                    linear.outputs.extend_from_slice(self.value(index));
+                }
+            }
+            Interpolation::CubicSpline => {
+                for window in self.inputs.windows(2) {
+                    self.subdivide(window[0], window[1], tolerance, 0, &mut linear);
+                }
+            }
+        }
+        linear
+    }
+
+    /// Appends linear keyframes approximating the spline from `start` to
+    /// `end`, excluding `start`.
+    fn subdivide(&self, start: f32, end: f32, tolerance: f32, depth: u32, linear: &mut Keyframes) {
+        // Limits each keyframe interval to 2^12 linear segments.
+        const MAX_DEPTH: u32 = 12;
+
+        let start_value = self.sample_components(start);
+        let end_value = self.sample_components(end);
+        let within_tolerance = [0.25, 0.5, 0.75].iter().all(|t| {
+            let approximation = self.lerp(&start_value, &end_value, *t);
+            let exact = self.sample_components(start + (end - start) * t);
+            self.error(&approximation, &exact) <= tolerance
+        });
+        if within_tolerance || depth == MAX_DEPTH {
+            linear.inputs.push(end);
+            linear.outputs.extend(end_value);
+        } else {
+            let middle = start + (end - start) * 0.5;
+            self.subdivide(start, middle, tolerance, depth + 1, linear);
+            self.subdivide(middle, end, tolerance, depth + 1, linear);
+        }
+    }
+
+    /// Measures the difference between two values of the animated property.
+    fn error(&self, a: &[f32], b: &[f32]) -> f32 {
+        match self.property {
+            Some(Property::Translation) => a
+                .iter()
+                .zip(b)
+                .map(|(a, b)| (a - b) * (a - b))
+                .sum::<f32>()
+                .sqrt(),
+            Some(Property::Rotation) => {
+                let dot = a.iter().zip(b).map(|(a, b)| a * b).sum::<f32>();
+                2.0 * dot.abs().min(1.0).acos()
+            }
+            Some(Property::Scale) => a
+                .iter()
+                .zip(b)
+                .map(|(a, b)| (a - b).abs() / b.abs().max(f32::EPSILON))
+                .fold(0.0, f32::max),
+            _ => a
+                .iter()
+                .zip(b)
+                .map(|(a, b)| (a - b).abs())
+                .fold(0.0, f32::max),
+        }
+    }
+
+    /// Appends the keyframes to `data` as `f32` values, adding a buffer view
+    /// and an accessor to `root` for both the inputs and the outputs.
+    ///
+    /// `data` holds the contents of `buffer`, whose byte length is updated.
+    /// Returns the indices of the input and output accessors.
+    pub fn write(
+        &self,
+        root: &mut json::Root,
+        buffer: json::Index<json::Buffer>,
+        data: &mut Vec<u8>,
+    ) -> (json::Index<json::Accessor>, json::Index<json::Accessor>) {
+        use json::accessor::Type;
+
+        let bounds = Some((
+            vec![self.inputs[0]],
+            vec![self.inputs[self.inputs.len() - 1]],
+        ));
+        let input = write_accessor(root, buffer, data, &self.inputs, Type::Scalar, bounds);
+
+        let type_ = match (self.property, self.width) {
+            (Some(Property::MorphTargetWeights), _) | (_, 1) => Type::Scalar,
+            (_, 2) => Type::Vec2,
+            (_, 3) => Type::Vec3,
+            (_, 4) => Type::Vec4,
+            _ => Type::Scalar,
+        };
+        let output = write_accessor(root, buffer, data, &self.outputs, type_, None);
+        (input, output)
+    }
+}
+
+/// Returns the largest `f32` smaller than `time`.
+fn next_down(time: f32) -> f32 {
+    if time > 0.0 {
+        f32::from_bits(time.to_bits() - 1)
+    } else if time < 0.0 {
+        f32::from_bits(time.to_bits() + 1)
+    } else {
+        -f32::from_bits(1)
+    }
+}
+
+/// Appends `values` to `data` with a new buffer view and accessor.
+fn write_accessor(
+    root: &mut json::Root,
+    buffer: json::Index<json::Buffer>,
+    data: &mut Vec<u8>,
+    values: &[f32],
+    type_: json::accessor::Type,
+    bounds: Option<(Vec<f32>, Vec<f32>)>,
+) -> json::Index<json::Accessor> {
+    data.resize((data.len() + 3) & !3, 0);
+    let byte_offset = data.len() as u32;
+    for value in values {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    root.buffers[buffer.value()].byte_length = data.len() as u32;
+
+    root.buffer_views.push(json::buffer::View {
+        buffer,
+        byte_length: data.len() as u32 - byte_offset,
+        byte_offset: Some(byte_offset),
+        byte_stride: None,
+        extensions: Default::default(),
+        extras: Default::default(),
+        #[cfg(feature = "names")]
+        name: None,
+        target: None,
+    });
+    let (min, max) = match bounds {
+        Some((min, max)) => (Some(json::Value::from(min)), Some(json::Value::from(max))),
+        None => (None, None),
+    };
+    root.accessors.push(json::Accessor {
+        buffer_view: Some(json::Index::new(root.buffer_views.len() as u32 - 1)),
+        byte_offset: 0,
+        count: (values.len() / type_.multiplicity()) as u32,
+        component_type: Valid(json::accessor::GenericComponentType(
+            json::accessor::ComponentType::F32,
+        )),
+        extensions: Default::default(),
+        extras: Default::default(),
+        type_: Valid(type_),
+        min,
+        max,
+        #[cfg(feature = "names")]
+        name: None,
+        normalized: false,
+        sparse: None,
+    });
+    json::Index::new(root.accessors.len() as u32 - 1)
+}
+
+/// Rewrites the keyframes of every animation channel of a document.
+///
+/// `rewrite` receives each channel with its decoded keyframes, for example
+/// to `resample` or `reduce` them, or to convert them `to_linear`. The
+/// results are stored as `f32` at the end of the buffer with index
+/// `buffer`, and the samplers are pointed at the new accessors. The
+/// accessors they previously used are left in place.
+///
+/// Returns the rewritten JSON together with the complete new contents of
+/// the target buffer, which the caller is responsible for storing. For a
+/// binary glTF, pass `0` to extend the `BIN` chunk. Passing the number of
+/// buffers in the document instead appends a new buffer with no `uri`,
+/// which is only valid in a binary glTF if it is the first buffer, so a
+/// `uri` must be set on it before the document is written. Returns `None`
+/// if `buffer` is out of range or the data of an existing target buffer is
+/// unavailable.
+///
+/// Samplers shared by several channels are rewritten once, for the first
+/// channel using them. Channels whose keyframes cannot be read are left
+/// unchanged.
+pub fn rewrite_keyframes<'a, 's, F, R>(
+    document: &'a Document,
+    get_buffer_data: F,
+    buffer: usize,
+    mut rewrite: R,
+) -> Option<(json::Root, Vec<u8>)>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    R: FnMut(&Channel<'a>, Keyframes) -> Keyframes,
+{
+    let mut root = document.0.clone();
+    let mut data = match document.buffers().nth(buffer) {
+        Some(existing) => {
+            let length = existing.length();
+            get_buffer_data(existing)?.get(..length)?.to_vec()
+        }
+        None if buffer == root.buffers.len() => {
+            root.buffers.push(json::Buffer {
+                byte_length: 0,
+                extensions: Default::default(),
+                extras: Default::default(),
+                #[cfg(feature = "names")]
+                name: None,
+                uri: None,
+            });
+            Vec::new()
+        }
+        None => return None,
+    };
+    let buffer = json::Index::new(buffer as u32);
+
+    for animation in document.animations() {
+        let mut rewritten = vec![false; animation.json.samplers.len()];
+        for channel in animation.channels() {
+            let sampler = channel.json.sampler.value();
+            if rewritten[sampler] {
+                continue;
+            }
+            let keyframes = match channel.reader(get_buffer_data.clone()).read_keyframes() {
+                Some(keyframes) => keyframes,
+                None => continue,
+            };
+            let keyframes = rewrite(&channel, keyframes);
+            let (input, output) = keyframes.write(&mut root, buffer, &mut data);
+            let json = &mut root.animations[animation.index()].samplers[sampler];
+            json.input = input;
+            json.output = output;
+            json.interpolation = Valid(keyframes.interpolation);
+            rewritten[sampler] = true;
+        }
+    }
+    Some((root, data))
+}
@@ -0,0 +1,126 @@
+use crate::math::Matrix4;
+use crate::scene::{Node, Transform};
+use crate::Document;
+
+/// The local transforms and morph weights of the nodes targeted by an
+/// animation at a point in time.
+#[derive(Clone, Debug)]
+pub struct Pose<'a> {
+    pub(crate) document: &'a Document,
+    pub(crate) nodes: Vec<NodePose<'a>>,
+}
+
+/// The sampled state of a single animated node.
+///
+/// Properties without a channel keep the rest value of the node.
+#[derive(Clone, Debug)]
+pub struct NodePose<'a> {
+    pub(crate) node: Node<'a>,
+
+    /// The local `[x, y, z]` translation.
+    pub translation: [f32; 3],
+
+    /// The local `[x, y, z, w]` rotation quaternion.
+    pub rotation: [f32; 4],
+
+    /// The local `[x, y, z]` scale.
+    pub scale: [f32; 3],
+
+    /// The morph target weights, or `None` if the node has no mesh and no
+    /// animated weights.
+    pub weights: Option<Vec<f32>>,
+}
+
+impl<'a> Pose<'a> {
+    /// Returns the poses of the animated nodes, in the order in which they
+    /// are first targeted by the channels of the animation.
+    pub fn nodes(&self) -> &[NodePose<'a>] {
+        &self.nodes
+    }
+
+    /// Returns the pose of the node at `index`, if the animation targets it.
+    pub fn get(&self, index: usize) -> Option<&NodePose<'a>> {
+        self.nodes.iter().find(|pose| pose.node.index() == index)
+    }
+
+    /// Returns the world transform of every node of the document, indexed by
+    /// node, with the local transforms of the animated nodes replaced by
+    /// their pose.
+    pub fn world_matrices(&self) -> Vec<[[f32; 4]; 4]> {
+        let local_matrix = |node: &Node<'a>| {
+            let transform = match self.get(node.index()) {
+                Some(pose) => pose.transform(),
+                None => node.transform(),
+            };
+            Matrix4::from_array(transform.matrix())
+        };
+        self.document
+            .nodes()
+            .map(|node| {
+                let mut matrix = local_matrix(&node);
+                for ancestor in node.ancestors() {
+                    matrix = local_matrix(&ancestor) * matrix;
+                }
+                matrix.as_array()
+            })
+            .collect()
+    }
+
+    /// Returns the pose of `node`, inserting its rest pose if it is missing.
+    pub(crate) fn entry(&mut self, node: Node<'a>) -> &mut NodePose<'a> {
+        let position = self
+            .nodes
+            .iter()
+            .position(|pose| pose.node.index() == node.index());
+        match position {
+            Some(position) => &mut self.nodes[position],
+            None => {
+                self.nodes.push(NodePose::rest(node));
+                self.nodes.last_mut().unwrap()
+            }
+        }
+    }
+}
+
+impl<'a> NodePose<'a> {
+    /// Constructs the pose of a node from its rest transform and weights.
+    fn rest(node: Node<'a>) -> Self {
+        let (translation, rotation, scale) = node.transform().decomposed();
+        let weights = match (node.weights(), node.mesh()) {
+            (Some(weights), _) => Some(weights.to_vec()),
+            (None, Some(mesh)) => match mesh.weights() {
+                Some(weights) => Some(weights.to_vec()),
+                None => {
+                    // Morph targets default to a weight of zero.
+                    let targets = mesh
+                        .primitives()
+                        .next()
+                        .map_or(0, |primitive| primitive.morph_targets().len());
+                    Some(vec![0.0; targets])
+                }
+            },
+            (None, None) => None,
+        };
+        Self {
+            node,
+            translation,
+            rotation,
+            scale,
+            weights,
+        }
+    }
+
+    /// Returns the posed node.
+    pub fn node(&self) -> Node<'a> {
+        self.node.clone()
+    }
+
+    /// Returns the local transform of the pose.
+    pub fn transform(&self) -> Transform {
+        Transform::Decomposed {
+            translation: self.translation,
+            rotation: self.rotation,
+            scale: self.scale,
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use crate::animation::{Interpolation, Property};
+
+/// The keyframes of an animation channel, decoded to `f32`.
+///
+/// Quantized rotations and morph target weights are normalized when read, so
+/// every output is stored as floating point.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Keyframes {
+    pub(crate) property: Option<Property>,
+    pub(crate) interpolation: Interpolation,
+    pub(crate) inputs: Vec<f32>,
+    pub(crate) outputs: Vec<f32>,
+    pub(crate) width: usize,
+}
+
+/// A value sampled from an animation channel.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// XYZ translation.
+    Translation([f32; 3]),
+
+    /// XYZW rotation quaternion of unit length.
+    Rotation([f32; 4]),
+
+    /// XYZ scale.
+    Scale([f32; 3]),
+
+    /// Morph target weights.
+    MorphTargetWeights(Vec<f32>),
+
+    /// Components of any other property, such as one addressed by
+    /// `KHR_animation_pointer`.
+    Components(Vec<f32>),
+}
+
+impl Keyframes {
+    /// Constructs `Keyframes` from outputs flattened from elements of `width`
+    /// components, returning `None` if there are no inputs, the inputs do
+    /// not strictly increase or the number of outputs does not match the
+    /// number of inputs.
+    pub(crate) fn new(
+        property: Option<Property>,
+        interpolation: Interpolation,
+        inputs: Vec<f32>,
+        outputs: Vec<f32>,
+        width: usize,
+    ) -> Option<Self> {
+        let elements = match interpolation {
+            Interpolation::CubicSpline => 3 * inputs.len(),
+            _ => inputs.len(),
+        };
+        if elements == 0 || width == 0 || outputs.len() != elements * width {
+            return None;
+        }
+        // Equal or decreasing times would make the interpolation factor
+        // between them undefined.
+        if !inputs.windows(2).all(|pair| pair[0] < pair[1]) {
+            return None;
+        }
+        Some(Self {
+            property,
+            interpolation,
+            inputs,
+            outputs,
+            width,
+        })
+    }
+
+    /// Returns the animated node property, or `None` for other properties
+    /// addressed by `KHR_animation_pointer`.
+    pub fn property(&self) -> Option<Property> {
+        self.property
+    }
+
+    /// Returns the keyframe interpolation algorithm.
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// Returns the keyframe times.
+    pub fn inputs(&self) -> &[f32] {
+        &self.inputs
+    }
+
+    /// Returns the flattened keyframe outputs.
+    ///
+    /// For cubic spline interpolation each keyframe stores an in-tangent, a
+    /// value and an out-tangent, in that order.
+    pub fn outputs(&self) -> &[f32] {
+        &self.outputs
+    }
+
+    /// Returns the number of components of each output element, for example
+    /// `4` for rotations or the number of morph targets for weights.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Evaluates the channel at `time`.
+    ///
+    /// Times before the first keyframe yield the first value and times after
+    /// the last keyframe yield the last value. Rotations are interpolated
+    /// along the shortest arc and always have unit length.
+    pub fn sample(&self, time: f32) -> Value {
+        self.to_value(self.sample_components(time))
+    }
+
+    /// Evaluates the channel at `time`, returning the flattened components of
+    /// the value.
+    pub(crate) fn sample_components(&self, time: f32) -> Vec<f32> {
+        let last = self.inputs.len() - 1;
+        if time.is_nan() || time <= self.inputs[0] {
+            return self.value(0).to_vec();
+        }
+        if time >= self.inputs[last] {
+            return self.value(last).to_vec();
+        }
+
+        // Binary search for the first keyframe after `time`.
+        let (mut next, mut end) = (1, last);
+        while next < end {
+            let middle = (next + end) / 2;
+            if self.inputs[middle] <= time {
+                next = middle + 1;
+            } else {
+                end = middle;
+            }
+        }
+        let previous = next - 1;
+        let delta = self.inputs[next] - self.inputs[previous];
+        let t = (time - self.inputs[previous]) / delta;
+        match self.interpolation {
+            Interpolation::CubicSpline => {
+                let t2 = t * t;
+                let t3 = t2 * t;
+                let value_start = 2.0 * t3 - 3.0 * t2 + 1.0;
+                let tangent_start = delta * (t3 - 2.0 * t2 + t);
+                let value_end = -2.0 * t3 + 3.0 * t2;
+                let tangent_end = delta * (t3 - t2);
+                let mut components = (0..self.width)
+                    .map(|i| {
+                        value_start * self.value(previous)[i]
+                            + tangent_start * self.element(3 * previous + 2)[i]
+                            + value_end * self.value(next)[i]
+                            + tangent_end * self.element(3 * next)[i]
+                    })
+                    .collect::<Vec<_>>();
+                if self.property == Some(Property::Rotation) {
+                    normalize(&mut components);
+                }
+                components
+            }
+            _ => self.interpolate(previous, next, t),
+        }
+    }
+
+    /// Interpolates between the values of two keyframes without tangents,
+    /// holding the first value for step interpolation.
+    pub(crate) fn interpolate(&self, from: usize, to: usize, t: f32) -> Vec<f32> {
+        match self.interpolation {
+            Interpolation::Step => self.value(from).to_vec(),
+            _ => self.lerp(self.value(from), self.value(to), t),
+        }
+    }
+
+    /// Linearly interpolates between two values of the animated property,
+    /// using slerp for rotations.
+    pub(crate) fn lerp(&self, a: &[f32], b: &[f32], t: f32) -> Vec<f32> {
+        if self.property == Some(Property::Rotation) {
+            slerp(a, b, t).to_vec()
+        } else {
+            a.iter().zip(b).map(|(a, b)| a + (b - a) * t).collect()
+        }
+    }
+
+    /// Returns the output element at `index`.
+    pub(crate) fn element(&self, index: usize) -> &[f32] {
+        &self.outputs[index * self.width..(index + 1) * self.width]
+    }
+
+    /// Returns the value of the keyframe at `index`, skipping the tangents of
+    /// cubic splines.
+    pub(crate) fn value(&self, index: usize) -> &[f32] {
+        match self.interpolation {
+            Interpolation::CubicSpline => self.element(3 * index + 1),
+            _ => self.element(index),
+        }
+    }
+
+    /// Wraps flattened components in the `Value` variant of the property.
+    pub(crate) fn to_value(&self, components: Vec<f32>) -> Value {
+        let vec3 = |c: &[f32]| [c[0], c[1], c[2]];
+        match self.property {
+            Some(Property::Translation) => Value::Translation(vec3(&components)),
+            Some(Property::Rotation) => {
+                Value::Rotation([components[0], components[1], components[2], components[3]])
+            }
+            Some(Property::Scale) => Value::Scale(vec3(&components)),
+            Some(Property::MorphTargetWeights) => Value::MorphTargetWeights(components),
+            None => Value::Components(components),
+        }
+    }
+}
+
+/// Spherical linear interpolation between two quaternions, taking the
+/// shortest arc.
+fn slerp(a: &[f32], b: &[f32], t: f32) -> [f32; 4] {
+    let mut dot = a.iter().zip(b).map(|(a, b)| a * b).sum::<f32>();
+    let sign = if dot < 0.0 {
+        dot = -dot;
+        -1.0
+    } else {
+        1.0
+    };
+    // Nearly parallel quaternions fall back to a normalized lerp, avoiding a
+    // division by a vanishing sine.
+    let (weight_a, weight_b) = if dot > 0.9995 {
+        (1.0 - t, t)
+    } else {
+        let theta = dot.acos();
+        let sin = theta.sin();
+        (((1.0 - t) * theta).sin() / sin, (t * theta).sin() / sin)
+    };
+    let mut result = [0.0; 4];
+    for (out, (a, b)) in result.iter_mut().zip(a.iter().zip(b)) {
+        *out = weight_a * a + sign * weight_b * b;
+    }
+    normalize(&mut result);
+    result
+}
+
+fn normalize(components: &mut [f32]) {
+    let length = components.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if length > 0.0 {
+        for x in components {
+            *x /= length;
+        }
+    }
+}
@@ -10,6 +10,24 @@ pub enum Projection<'a> {
     Perspective(Perspective<'a>),
 }
 
+/// Options for building a projection matrix.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ProjectionOptions {
+    /// The aspect ratio (width over height) of the viewport, used instead of
+    /// the aspect ratio of the camera.
+    ///
+    /// Perspective cameras without an aspect ratio use `1.0` if this is
+    /// `None`. Orthographic cameras keep their `ymag` and derive `xmag` from
+    /// the overriding aspect ratio.
+    pub aspect_ratio: Option<f32>,
+
+    /// Map the near plane to a depth of `1` and the far plane to a depth of
+    /// `0`, as used with reversed-Z depth buffers.
+    ///
+    /// Otherwise depth is mapped to `[-1, 1]` as in the glTF specification.
+    pub reversed_z: bool,
+}
+
 /// A camera's projection.  A node can reference a camera to apply a transform to
 /// place the camera in the scene.
 #[derive(Clone, Debug)]
@@ -86,12 +104,27 @@ impl<'a> Camera<'a> {
         }
     }
 
+    /// Returns the column-major projection matrix of the camera.
+    pub fn projection_matrix(&self, options: ProjectionOptions) -> [[f32; 4]; 4] {
+        self.projection().matrix(options)
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
     }
 }
 
+impl<'a> Projection<'a> {
+    /// Returns the column-major projection matrix.
+    pub fn matrix(&self, options: ProjectionOptions) -> [[f32; 4]; 4] {
+        match self {
+            Projection::Orthographic(orthographic) => orthographic.matrix(options),
+            Projection::Perspective(perspective) => perspective.matrix(options),
+        }
+    }
+}
+
 impl<'a> Orthographic<'a> {
     /// Constructs a `Orthographic` camera projection.
     pub(crate) fn new(document: &'a Document, json: &'a json::camera::Orthographic) -> Self {
@@ -118,6 +151,26 @@ impl<'a> Orthographic<'a> {
         self.json.znear
     }
 
+    /// Returns the column-major orthographic projection matrix.
+    pub fn matrix(&self, options: ProjectionOptions) -> [[f32; 4]; 4] {
+        let ymag = self.ymag();
+        let xmag = options
+            .aspect_ratio
+            .map_or(self.xmag(), |aspect_ratio| ymag * aspect_ratio);
+        let (n, f) = (self.znear(), self.zfar());
+        let (z, w) = if options.reversed_z {
+            (1.0 / (f - n), f / (f - n))
+        } else {
+            (2.0 / (n - f), (f + n) / (n - f))
+        };
+        [
+            [1.0 / xmag, 0.0, 0.0, 0.0],
+            [0.0, 1.0 / ymag, 0.0, 0.0],
+            [0.0, 0.0, z, 0.0],
+            [0.0, 0.0, w, 1.0],
+        ]
+    }
+
     ///  Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
@@ -150,6 +203,29 @@ impl<'a> Perspective<'a> {
         self.json.znear
     }
 
+    /// Returns the column-major perspective projection matrix, with an
+    /// infinite far plane if `zfar` is undefined.
+    pub fn matrix(&self, options: ProjectionOptions) -> [[f32; 4]; 4] {
+        let aspect_ratio = options
+            .aspect_ratio
+            .or_else(|| self.aspect_ratio())
+            .unwrap_or(1.0);
+        let focal_length = 1.0 / (0.5 * self.yfov()).tan();
+        let n = self.znear();
+        let (z, w) = match (self.zfar(), options.reversed_z) {
+            (Some(f), false) => ((f + n) / (n - f), 2.0 * f * n / (n - f)),
+            (Some(f), true) => (n / (f - n), f * n / (f - n)),
+            (None, false) => (-1.0, -2.0 * n),
+            (None, true) => (0.0, n),
+        };
+        [
+            [focal_length / aspect_ratio, 0.0, 0.0, 0.0],
+            [0.0, focal_length, 0.0, 0.0],
+            [0.0, 0.0, z, -1.0],
+            [0.0, 0.0, w, 0.0],
+        ]
+    }
+
     ///  Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
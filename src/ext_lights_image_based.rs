@@ -0,0 +1,137 @@
+use crate::{Document, Image};
+use gltf_json::Extras;
+use std::slice;
+
+/// An environment light defined by prefiltered specular cube maps and
+/// irradiance spherical harmonics.
+#[derive(Clone, Debug)]
+pub struct ImageBasedLight<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::scene::ext_lights_image_based::ImageBasedLight,
+}
+
+/// An `Iterator` that visits the specular cube map of each mip level, from
+/// largest to smallest.
+///
+/// Yields `None` for a mip level that does not list exactly six valid faces.
+#[derive(Clone, Debug)]
+pub struct SpecularImages<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The internal mip level iterator.
+    iter: slice::Iter<'a, Vec<json::Index<json::Image>>>,
+}
+
+impl<'a> ImageBasedLight<'a> {
+    /// Constructs an `ImageBasedLight`.
+    pub(crate) fn new(
+        document: &'a Document,
+        index: usize,
+        json: &'a json::extensions::scene::ext_lights_image_based::ImageBasedLight,
+    ) -> Self {
+        Self {
+            document,
+            index,
+            json,
+        }
+    }
+
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Optional user-defined name for this object.
+    #[cfg(feature = "names")]
+    pub fn name(&self) -> Option<&'a str> {
+        self.json.name.as_deref()
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a Extras {
+        &self.json.extras
+    }
+
+    /// XYZW quaternion rotating the environment.
+    pub fn rotation(&self) -> [f32; 4] {
+        self.json.rotation
+    }
+
+    /// Brightness multiplier for the environment.
+    pub fn intensity(&self) -> f32 {
+        self.json.intensity
+    }
+
+    /// The nine spherical harmonic coefficients for the irradiance of the
+    /// environment, as linear RGB triples.
+    pub fn irradiance_coefficients(&self) -> &'a [[f32; 3]] {
+        &self.json.irradiance_coefficients
+    }
+
+    /// The largest dimension, in pixels, of the first specular mip level.
+    pub fn specular_image_size(&self) -> u32 {
+        self.json.specular_image_size
+    }
+
+    /// Returns the number of specular mip levels.
+    pub fn specular_mip_count(&self) -> usize {
+        self.json.specular_images.len()
+    }
+
+    /// Returns the image of a single specular cube map face.
+    ///
+    /// Faces are ordered +X, -X, +Y, -Y, +Z, -Z.
+    pub fn specular_image(&self, mip: usize, face: usize) -> Option<Image<'a>> {
+        let index = self.json.specular_images.get(mip)?.get(face)?;
+        self.document.images().nth(index.value())
+    }
+
+    /// Returns an `Iterator` that visits the specular cube map of each mip
+    /// level, from largest to smallest.
+    ///
+    /// A mip level that does not list exactly six valid faces yields `None`.
+    pub fn specular_images(&self) -> SpecularImages<'a> {
+        SpecularImages {
+            document: self.document,
+            iter: self.json.specular_images.iter(),
+        }
+    }
+}
+
+impl<'a> SpecularImages<'a> {
+    fn cube(&self, faces: &'a [json::Index<json::Image>]) -> Option<[Image<'a>; 6]> {
+        let image = |face: &json::Index<json::Image>| self.document.images().nth(face.value());
+        match faces {
+            [px, nx, py, ny, pz, nz] => Some([
+                image(px)?,
+                image(nx)?,
+                image(py)?,
+                image(ny)?,
+                image(pz)?,
+                image(nz)?,
+            ]),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for SpecularImages<'a> {}
+impl<'a> Iterator for SpecularImages<'a> {
+    type Item = Option<[Image<'a>; 6]>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|faces| self.cube(faces))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(|faces| self.cube(faces))
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{Document, Primitive, Texture};
+use std::{iter, slice};
+
+#[cfg(feature = "utils")]
+use crate::Buffer;
+
+#[cfg(feature = "import")]
+use crate::image;
+
+/// Utility functions.
+#[cfg(feature = "utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+pub mod util;
+
+#[cfg(feature = "utils")]
+#[doc(inline)]
+pub use self::util::Reader;
+
+/// A set of feature IDs of a primitive.
+///
+/// When neither an attribute nor a texture is provided, the feature ID of
+/// each vertex is its index.
+#[derive(Clone, Debug)]
+pub struct FeatureId<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The primitive the feature IDs belong to.
+    primitive: Primitive<'a>,
+
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::mesh::ext_mesh_features::FeatureId,
+}
+
+/// A texture containing feature IDs.
+#[derive(Clone, Debug)]
+pub struct FeatureIdTexture<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::mesh::ext_mesh_features::FeatureIdTexture,
+}
+
+/// An `Iterator` that visits the feature ID sets of a primitive.
+#[derive(Clone, Debug)]
+pub struct FeatureIds<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The primitive the feature IDs belong to.
+    primitive: Primitive<'a>,
+
+    /// The internal feature ID iterator.
+    iter: iter::Enumerate<slice::Iter<'a, json::extensions::mesh::ext_mesh_features::FeatureId>>,
+}
+
+impl<'a> FeatureId<'a> {
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the primitive the feature IDs belong to.
+    pub fn primitive(&self) -> Primitive<'a> {
+        self.primitive.clone()
+    }
+
+    /// Returns the number of unique features in the set.
+    pub fn feature_count(&self) -> u32 {
+        self.json.feature_count
+    }
+
+    /// Returns the value indicating that no feature is associated with a
+    /// vertex or texel.
+    pub fn null_feature_id(&self) -> Option<u32> {
+        self.json.null_feature_id
+    }
+
+    /// Returns the label identifying the set of feature IDs.
+    pub fn label(&self) -> Option<&'a str> {
+        self.json.label.as_deref()
+    }
+
+    /// Returns the set index of the `_FEATURE_ID_n` vertex attribute
+    /// containing the feature IDs.
+    pub fn attribute(&self) -> Option<u32> {
+        self.json.attribute
+    }
+
+    /// Returns the texture containing the feature IDs.
+    pub fn texture(&self) -> Option<FeatureIdTexture<'a>> {
+        self.json.texture.as_ref().map(|json| FeatureIdTexture {
+            document: self.document,
+            json,
+        })
+    }
+
+    /// Returns the property table containing per-feature metadata.
+    #[cfg(feature = "EXT_structural_metadata")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_structural_metadata")))]
+    pub fn property_table(&self) -> Option<crate::ext_structural_metadata::PropertyTable<'a>> {
+        let index = self.json.property_table? as usize;
+        self.document
+            .structural_metadata()?
+            .property_tables()
+            .nth(index)
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+
+    /// Constructs a feature ID reader.
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn reader<'s, F>(&self, get_buffer_data: F) -> Reader<'a, 's, F>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        Reader {
+            feature_id: self.clone(),
+            get_buffer_data,
+        }
+    }
+}
+
+impl<'a> FeatureIdTexture<'a> {
+    /// Returns the texture containing the feature IDs.
+    pub fn texture(&self) -> Texture<'a> {
+        self.document
+            .textures()
+            .nth(self.json.index.value())
+            .unwrap()
+    }
+
+    /// Returns the set index of the texture's `TEXCOORD` attribute.
+    pub fn tex_coord(&self) -> u32 {
+        self.json.tex_coord
+    }
+
+    /// Returns the texture channels containing the feature ID, from least to
+    /// most significant byte.
+    pub fn channels(&self) -> &'a [u32] {
+        &self.json.channels
+    }
+
+    /// Returns the feature ID of the texel nearest to the given texture
+    /// co-ordinates, wrapping them with the texture sampler.
+    ///
+    /// Returns `None` unless the image data has 8 bits per channel and
+    /// contains every referenced channel.
+    #[cfg(feature = "import")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+    pub fn sample(&self, data: &image::Data, tex_coord: [f32; 2]) -> Option<u32> {
+        let channel_count = match data.format {
+            image::Format::R8 => 1,
+            image::Format::R8G8 => 2,
+            image::Format::R8G8B8 => 3,
+            image::Format::R8G8B8A8 => 4,
+            _ => return None,
+        };
+        let sampler = self.texture().sampler();
+        let x = wrap(tex_coord[0], data.width, sampler.wrap_s())?;
+        let y = wrap(tex_coord[1], data.height, sampler.wrap_t())?;
+        let texel = (y * data.width as usize + x) * channel_count;
+        let mut id = 0;
+        for (i, &channel) in self.json.channels.iter().enumerate() {
+            if channel as usize >= channel_count {
+                return None;
+            }
+            let value = *data.pixels.get(texel + channel as usize)?;
+            id |= (value as u32) << (8 * i);
+        }
+        Some(id)
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> FeatureIds<'a> {
+    /// Constructs a `FeatureIds` iterator over the feature ID sets of a
+    /// primitive.
+    pub(crate) fn new(
+        document: &'a Document,
+        primitive: Primitive<'a>,
+        json: &'a [json::extensions::mesh::ext_mesh_features::FeatureId],
+    ) -> Self {
+        Self {
+            document,
+            primitive,
+            iter: json.iter().enumerate(),
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for FeatureIds<'a> {}
+impl<'a> Iterator for FeatureIds<'a> {
+    type Item = FeatureId<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(index, json)| FeatureId {
+            document: self.document,
+            primitive: self.primitive.clone(),
+            index,
+            json,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Maps a texture co-ordinate to the nearest texel, wrapping it with the given
+/// mode.
+#[cfg(feature = "import")]
+fn wrap(coord: f32, size: u32, mode: crate::texture::WrappingMode) -> Option<usize> {
+    use crate::texture::WrappingMode;
+    let size = size as i64;
+    if size == 0 {
+        return None;
+    }
+    let texel = (coord * size as f32).floor() as i64;
+    let texel = match mode {
+        WrappingMode::ClampToEdge => texel.clamp(0, size - 1),
+        WrappingMode::Repeat => texel.rem_euclid(size),
+        WrappingMode::MirroredRepeat => {
+            let texel = texel.rem_euclid(2 * size);
+            if texel < size {
+                texel
+            } else {
+                2 * size - 1 - texel
+            }
+        }
+    };
+    Some(texel as usize)
+}
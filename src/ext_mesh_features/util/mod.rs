@@ -0,0 +1,127 @@
+use crate::accessor::{DataType, Iter};
+use crate::ext_mesh_features::FeatureId;
+use crate::{Buffer, Semantic};
+use std::ops::Range;
+
+#[cfg(feature = "import")]
+use crate::image;
+
+/// Feature ID reader.
+#[derive(Clone, Debug)]
+pub struct Reader<'a, 's, F>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    pub(crate) feature_id: FeatureId<'a>,
+    pub(crate) get_buffer_data: F,
+}
+
+/// An `Iterator` that yields the feature ID of each vertex.
+#[derive(Clone, Debug)]
+pub enum ReadFeatureIds<'a> {
+    /// Feature IDs stored as `u8`.
+    U8(Iter<'a, u8>),
+    /// Feature IDs stored as `u16`.
+    U16(Iter<'a, u16>),
+    /// Feature IDs stored as `u32`.
+    U32(Iter<'a, u32>),
+    /// Feature IDs stored as whole `f32` values.
+    F32(Iter<'a, f32>),
+    /// Implicit feature IDs equal to the vertex index.
+    Implicit(Range<u32>),
+}
+
+impl<'a, 's, F> Reader<'a, 's, F>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    /// Visits the feature IDs stored in a vertex attribute, or the implicit
+    /// feature IDs when neither an attribute nor a texture is provided.
+    ///
+    /// Returns `None` for feature IDs stored in a texture.
+    pub fn read_feature_ids(&self) -> Option<ReadFeatureIds<'s>> {
+        let primitive = &self.feature_id.primitive;
+        match (self.feature_id.attribute(), self.feature_id.texture()) {
+            (Some(set), _) => {
+                let semantic = Semantic::Extras(format!("FEATURE_ID_{}", set));
+                let accessor = primitive.get(&semantic)?;
+                match accessor.data_type() {
+                    DataType::U8 => {
+                        Iter::new(accessor, self.get_buffer_data.clone()).map(ReadFeatureIds::U8)
+                    }
+                    DataType::U16 => {
+                        Iter::new(accessor, self.get_buffer_data.clone()).map(ReadFeatureIds::U16)
+                    }
+                    DataType::U32 => {
+                        Iter::new(accessor, self.get_buffer_data.clone()).map(ReadFeatureIds::U32)
+                    }
+                    DataType::F32 => {
+                        Iter::new(accessor, self.get_buffer_data.clone()).map(ReadFeatureIds::F32)
+                    }
+                    _ => None,
+                }
+            }
+            (None, None) => {
+                let count = primitive.get(&Semantic::Positions)?.count();
+                Some(ReadFeatureIds::Implicit(0..count as u32))
+            }
+            (None, Some(_)) => None,
+        }
+    }
+
+    /// Samples the feature ID texture at the texture co-ordinates of each
+    /// vertex, using the decoded image of the texture.
+    ///
+    /// Returns `None` if the feature IDs are not stored in a texture, the
+    /// texture co-ordinates cannot be read, or the image cannot be sampled.
+    #[cfg(feature = "import")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+    pub fn read_texture_feature_ids(&self, data: &image::Data) -> Option<Vec<u32>> {
+        use crate::mesh::util::ReadTexCoords;
+
+        let texture = self.feature_id.texture()?;
+        let semantic = Semantic::TexCoords(texture.tex_coord());
+        let accessor = self.feature_id.primitive.get(&semantic)?;
+        let tex_coords = match accessor.data_type() {
+            DataType::U8 => {
+                Iter::new(accessor, self.get_buffer_data.clone()).map(ReadTexCoords::U8)
+            }
+            DataType::U16 => {
+                Iter::new(accessor, self.get_buffer_data.clone()).map(ReadTexCoords::U16)
+            }
+            DataType::F32 => {
+                Iter::new(accessor, self.get_buffer_data.clone()).map(ReadTexCoords::F32)
+            }
+            _ => None,
+        }?;
+        tex_coords
+            .into_f32()
+            .map(|tex_coord| texture.sample(data, tex_coord))
+            .collect()
+    }
+}
+
+impl<'a> ExactSizeIterator for ReadFeatureIds<'a> {}
+impl<'a> Iterator for ReadFeatureIds<'a> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            ReadFeatureIds::U8(iter) => iter.next().map(u32::from),
+            ReadFeatureIds::U16(iter) => iter.next().map(u32::from),
+            ReadFeatureIds::U32(iter) => iter.next(),
+            ReadFeatureIds::F32(iter) => iter.next().map(|id| id as u32),
+            ReadFeatureIds::Implicit(iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            ReadFeatureIds::U8(iter) => iter.size_hint(),
+            ReadFeatureIds::U16(iter) => iter.size_hint(),
+            ReadFeatureIds::U32(iter) => iter.size_hint(),
+            ReadFeatureIds::F32(iter) => iter.size_hint(),
+            ReadFeatureIds::Implicit(iter) => iter.size_hint(),
+        }
+    }
+}
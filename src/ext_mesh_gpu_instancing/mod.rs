@@ -0,0 +1,109 @@
+use crate::{Accessor, Document, Node};
+use std::collections::btree_map;
+
+#[cfg(feature = "utils")]
+use crate::Buffer;
+
+/// Utility functions.
+#[cfg(feature = "utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+pub mod util;
+
+#[cfg(feature = "utils")]
+#[doc(inline)]
+pub use self::util::Reader;
+
+/// Per-instance attributes used to draw a node's mesh many times.
+#[derive(Clone, Debug)]
+pub struct MeshGpuInstancing<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The node that owns the instances.
+    node: Node<'a>,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::scene::ext_mesh_gpu_instancing::ExtMeshGpuInstancing,
+}
+
+/// An `Iterator` that visits the instance attributes of a node.
+#[derive(Clone, Debug)]
+pub struct Attributes<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The internal attribute iterator.
+    iter: btree_map::Iter<'a, String, json::Index<json::Accessor>>,
+}
+
+impl<'a> MeshGpuInstancing<'a> {
+    /// Constructs a `MeshGpuInstancing`.
+    pub(crate) fn new(
+        document: &'a Document,
+        node: Node<'a>,
+        json: &'a json::extensions::scene::ext_mesh_gpu_instancing::ExtMeshGpuInstancing,
+    ) -> Self {
+        Self {
+            document,
+            node,
+            json,
+        }
+    }
+
+    /// Returns the node that owns the instances.
+    pub fn node(&self) -> Node<'a> {
+        self.node.clone()
+    }
+
+    /// Returns the number of instances.
+    pub fn count(&self) -> usize {
+        self.attributes()
+            .next()
+            .map_or(0, |(_, accessor)| accessor.count())
+    }
+
+    /// Returns the accessor for the instance attribute with the given name,
+    /// such as `TRANSLATION` or an application specific `_ID`.
+    pub fn get(&self, name: &str) -> Option<Accessor<'a>> {
+        self.json
+            .attributes
+            .get(name)
+            .map(|index| self.document.accessors().nth(index.value()).unwrap())
+    }
+
+    /// Returns an `Iterator` over the instance attribute names and accessors.
+    pub fn attributes(&self) -> Attributes<'a> {
+        Attributes {
+            document: self.document,
+            iter: self.json.attributes.iter(),
+        }
+    }
+
+    /// Constructs an instance attribute reader.
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn reader<'s, F>(&self, get_buffer_data: F) -> Reader<'a, 's, F>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        Reader {
+            instancing: self.clone(),
+            get_buffer_data,
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for Attributes<'a> {}
+impl<'a> Iterator for Attributes<'a> {
+    type Item = (&'a str, Accessor<'a>);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(name, index)| {
+            let accessor = self.document.accessors().nth(index.value()).unwrap();
+            (name.as_str(), accessor)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -0,0 +1,257 @@
+/// Casting iterator adapters for instance translations and scales.
+pub mod vectors;
+
+use crate::accessor::{self, DataType, Iter};
+use crate::animation::util::{rotations, Rotations};
+use crate::ext_mesh_gpu_instancing::MeshGpuInstancing;
+use crate::scene::Transform;
+use crate::{Accessor, Buffer};
+
+/// Instance attribute reader.
+#[derive(Clone, Debug)]
+pub struct Reader<'a, 's, F>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    pub(crate) instancing: MeshGpuInstancing<'a>,
+    pub(crate) get_buffer_data: F,
+}
+
+/// XYZ instance translations or scales.
+#[derive(Clone, Debug)]
+pub enum Vectors<'a> {
+    /// Vectors of type `[i8; 3]`.
+    I8(accessor::Iter<'a, [i8; 3]>),
+    /// Vectors of type `[u8; 3]`.
+    U8(accessor::Iter<'a, [u8; 3]>),
+    /// Vectors of type `[i16; 3]`.
+    I16(accessor::Iter<'a, [i16; 3]>),
+    /// Vectors of type `[u16; 3]`.
+    U16(accessor::Iter<'a, [u16; 3]>),
+    /// Vectors of type `[f32; 3]`.
+    F32(accessor::Iter<'a, [f32; 3]>),
+}
+
+/// An `Iterator` that yields the decomposed transform of each instance.
+///
+/// Missing attributes default to the identity translation, rotation, or scale.
+/// Iteration stops early if an attribute accessor runs out of elements.
+#[derive(Clone, Debug)]
+pub struct Transforms<'a> {
+    translations: Option<Components<'a>>,
+    rotations: Option<rotations::CastingIter<'a, rotations::F32>>,
+    scales: Option<Components<'a>>,
+    remaining: usize,
+}
+
+/// An `Iterator` that yields the column-major transformation matrix of each
+/// instance.
+#[derive(Clone, Debug)]
+pub struct Matrices<'a>(Transforms<'a>);
+
+/// Instance vectors read as `f32`, normalized only when the accessor says so.
+#[derive(Clone, Debug)]
+enum Components<'a> {
+    Normalized(vectors::CastingIter<'a, vectors::F32>),
+    Unnormalized(vectors::CastingIter<'a, Unnormalized>),
+}
+
+/// Casts integer components to `f32` without normalization, as permitted for
+/// quantized translations and scales.
+#[derive(Clone, Debug)]
+struct Unnormalized;
+
+impl<'a> Vectors<'a> {
+    /// Reinterpret vectors as i8.  Lossy if underlying iterator yields u8,
+    /// i16, u16 or f32.
+    pub fn into_i8(self) -> vectors::CastingIter<'a, vectors::I8> {
+        vectors::CastingIter::new(self)
+    }
+
+    /// Reinterpret vectors as u8.  Lossy if underlying iterator yields i16,
+    /// u16 or f32.
+    pub fn into_u8(self) -> vectors::CastingIter<'a, vectors::U8> {
+        vectors::CastingIter::new(self)
+    }
+
+    /// Reinterpret vectors as i16.  Lossy if underlying iterator yields u16
+    /// or f32.
+    pub fn into_i16(self) -> vectors::CastingIter<'a, vectors::I16> {
+        vectors::CastingIter::new(self)
+    }
+
+    /// Reinterpret vectors as u16.  Lossy if underlying iterator yields f32.
+    pub fn into_u16(self) -> vectors::CastingIter<'a, vectors::U16> {
+        vectors::CastingIter::new(self)
+    }
+
+    /// Reinterpret vectors as f32.  Integer components are treated as
+    /// normalized.
+    pub fn into_f32(self) -> vectors::CastingIter<'a, vectors::F32> {
+        vectors::CastingIter::new(self)
+    }
+}
+
+impl<'a, 's, F> Reader<'a, 's, F>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    /// Visits the instance translations.
+    pub fn read_translations(&self) -> Option<Vectors<'s>> {
+        self.read_vectors(self.instancing.get("TRANSLATION")?)
+    }
+
+    /// Visits the instance rotations as XYZW quaternions.
+    pub fn read_rotations(&self) -> Option<Rotations<'s>> {
+        let accessor = self.instancing.get("ROTATION")?;
+        match accessor.data_type() {
+            DataType::I8 => Iter::new(accessor, self.get_buffer_data.clone()).map(Rotations::I8),
+            DataType::U8 => Iter::new(accessor, self.get_buffer_data.clone()).map(Rotations::U8),
+            DataType::I16 => Iter::new(accessor, self.get_buffer_data.clone()).map(Rotations::I16),
+            DataType::U16 => Iter::new(accessor, self.get_buffer_data.clone()).map(Rotations::U16),
+            DataType::F32 => Iter::new(accessor, self.get_buffer_data.clone()).map(Rotations::F32),
+            DataType::U32 => None,
+        }
+    }
+
+    /// Visits the instance scales.
+    pub fn read_scales(&self) -> Option<Vectors<'s>> {
+        self.read_vectors(self.instancing.get("SCALE")?)
+    }
+
+    /// Visits the decomposed transform of each instance, relative to the node
+    /// that owns the instances.
+    ///
+    /// Returns `None` if a `TRANSLATION`, `ROTATION`, or `SCALE` attribute is
+    /// present but its data cannot be read.
+    pub fn read_transforms(&self) -> Option<Transforms<'s>> {
+        let components = |name: &str| match self.instancing.get(name) {
+            Some(accessor) => {
+                let normalized = accessor.normalized();
+                let vectors = self.read_vectors(accessor)?;
+                Some(Some(if normalized {
+                    Components::Normalized(vectors::CastingIter::new(vectors))
+                } else {
+                    Components::Unnormalized(vectors::CastingIter::new(vectors))
+                }))
+            }
+            None => Some(None),
+        };
+        let rotations = match self.instancing.get("ROTATION") {
+            Some(_) => Some(self.read_rotations()?.into_f32()),
+            None => None,
+        };
+        Some(Transforms {
+            translations: components("TRANSLATION")?,
+            rotations,
+            scales: components("SCALE")?,
+            remaining: self.instancing.count(),
+        })
+    }
+
+    /// Visits the column-major transformation matrix of each instance,
+    /// relative to the node that owns the instances.
+    ///
+    /// Returns `None` under the same conditions as `read_transforms`.
+    pub fn read_matrices(&self) -> Option<Matrices<'s>> {
+        self.read_transforms().map(Matrices)
+    }
+
+    fn read_vectors(&self, accessor: Accessor<'a>) -> Option<Vectors<'s>> {
+        match accessor.data_type() {
+            DataType::I8 => Iter::new(accessor, self.get_buffer_data.clone()).map(Vectors::I8),
+            DataType::U8 => Iter::new(accessor, self.get_buffer_data.clone()).map(Vectors::U8),
+            DataType::I16 => Iter::new(accessor, self.get_buffer_data.clone()).map(Vectors::I16),
+            DataType::U16 => Iter::new(accessor, self.get_buffer_data.clone()).map(Vectors::U16),
+            DataType::F32 => Iter::new(accessor, self.get_buffer_data.clone()).map(Vectors::F32),
+            DataType::U32 => None,
+        }
+    }
+}
+
+impl<'a> Components<'a> {
+    fn next(&mut self) -> Option<[f32; 3]> {
+        match self {
+            Components::Normalized(iter) => iter.next(),
+            Components::Unnormalized(iter) => iter.next(),
+        }
+    }
+}
+
+impl vectors::Cast for Unnormalized {
+    type Output = [f32; 3];
+
+    fn cast_i8(x: [i8; 3]) -> Self::Output {
+        [f32::from(x[0]), f32::from(x[1]), f32::from(x[2])]
+    }
+
+    fn cast_u8(x: [u8; 3]) -> Self::Output {
+        [f32::from(x[0]), f32::from(x[1]), f32::from(x[2])]
+    }
+
+    fn cast_i16(x: [i16; 3]) -> Self::Output {
+        [f32::from(x[0]), f32::from(x[1]), f32::from(x[2])]
+    }
+
+    fn cast_u16(x: [u16; 3]) -> Self::Output {
+        [f32::from(x[0]), f32::from(x[1]), f32::from(x[2])]
+    }
+
+    fn cast_f32(x: [f32; 3]) -> Self::Output {
+        x
+    }
+}
+
+impl<'a> ExactSizeIterator for Transforms<'a> {}
+impl<'a> Iterator for Transforms<'a> {
+    type Item = Transform;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let translation = match self.translations.as_mut() {
+            Some(iter) => iter.next(),
+            None => Some([0.0, 0.0, 0.0]),
+        };
+        let rotation = match self.rotations.as_mut() {
+            Some(iter) => iter.next(),
+            None => Some([0.0, 0.0, 0.0, 1.0]),
+        };
+        let scale = match self.scales.as_mut() {
+            Some(iter) => iter.next(),
+            None => Some([1.0, 1.0, 1.0]),
+        };
+        match (translation, rotation, scale) {
+            (Some(translation), Some(rotation), Some(scale)) => {
+                self.remaining -= 1;
+                Some(Transform::Decomposed {
+                    translation,
+                    rotation,
+                    scale,
+                })
+            }
+            _ => {
+                self.remaining = 0;
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a> ExactSizeIterator for Matrices<'a> {}
+impl<'a> Iterator for Matrices<'a> {
+    type Item = [[f32; 4]; 4];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|transform| transform.matrix())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
@@ -0,0 +1,192 @@
+use crate::Document;
+use std::collections::btree_map;
+use std::{iter, slice};
+
+#[cfg(feature = "utils")]
+use crate::Buffer;
+
+/// Utility functions.
+#[cfg(feature = "utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+pub mod util;
+
+#[cfg(feature = "utils")]
+#[doc(inline)]
+pub use self::util::Reader;
+
+/// The metadata schema and property storage of an asset.
+#[derive(Clone, Debug)]
+pub struct StructuralMetadata<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::ext_structural_metadata::ExtStructuralMetadata,
+}
+
+/// Per-feature property values of a class, stored in buffer views.
+#[derive(Clone, Debug)]
+pub struct PropertyTable<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::ext_structural_metadata::PropertyTable,
+}
+
+/// An `Iterator` that visits the property tables of an asset.
+#[derive(Clone, Debug)]
+pub struct PropertyTables<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The internal property table iterator.
+    iter: iter::Enumerate<
+        slice::Iter<'a, json::extensions::root::ext_structural_metadata::PropertyTable>,
+    >,
+}
+
+impl<'a> StructuralMetadata<'a> {
+    /// Constructs a `StructuralMetadata`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::root::ext_structural_metadata::ExtStructuralMetadata,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// Returns the embedded schema, if the asset does not refer to an
+    /// external one.
+    pub fn schema(&self) -> Option<&'a json::extensions::root::ext_structural_metadata::Schema> {
+        self.json.schema.as_ref()
+    }
+
+    /// Returns the URI of the external schema, if any.
+    pub fn schema_uri(&self) -> Option<&'a str> {
+        self.json.schema_uri.as_deref()
+    }
+
+    /// Returns the class with the given identifier from the embedded schema.
+    pub fn class(
+        &self,
+        id: &str,
+    ) -> Option<&'a json::extensions::root::ext_structural_metadata::Class> {
+        self.schema()?.classes.get(id)
+    }
+
+    /// Returns the enum with the given identifier from the embedded schema.
+    pub fn enumeration(
+        &self,
+        id: &str,
+    ) -> Option<&'a json::extensions::root::ext_structural_metadata::Enum> {
+        self.schema()?.enums.get(id)
+    }
+
+    /// Returns an `Iterator` that visits the property tables.
+    pub fn property_tables(&self) -> PropertyTables<'a> {
+        PropertyTables {
+            document: self.document,
+            iter: self.json.property_tables.iter().enumerate(),
+        }
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> PropertyTable<'a> {
+    /// Constructs a `PropertyTable`.
+    pub(crate) fn new(
+        document: &'a Document,
+        index: usize,
+        json: &'a json::extensions::root::ext_structural_metadata::PropertyTable,
+    ) -> Self {
+        Self {
+            document,
+            index,
+            json,
+        }
+    }
+
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Optional user-defined name for this object.
+    #[cfg(feature = "names")]
+    pub fn name(&self) -> Option<&'a str> {
+        self.json.name.as_deref()
+    }
+
+    /// Returns the identifier of the class describing the properties.
+    pub fn class_id(&self) -> &'a str {
+        &self.json.class
+    }
+
+    /// Returns the class describing the properties, if the schema is
+    /// embedded in the asset.
+    pub fn class(&self) -> Option<&'a json::extensions::root::ext_structural_metadata::Class> {
+        self.document.structural_metadata()?.class(&self.json.class)
+    }
+
+    /// Returns the number of features, and so the number of rows of each
+    /// property.
+    pub fn count(&self) -> usize {
+        self.json.count as usize
+    }
+
+    /// Returns an `Iterator` over the identifiers of the stored properties.
+    pub fn property_ids(
+        &self,
+    ) -> btree_map::Keys<
+        'a,
+        String,
+        json::extensions::root::ext_structural_metadata::PropertyTableProperty,
+    > {
+        self.json.properties.keys()
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+
+    /// Constructs a property table reader.
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn reader<'s, F>(&self, get_buffer_data: F) -> Reader<'a, 's, F>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        Reader {
+            table: self.clone(),
+            get_buffer_data,
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for PropertyTables<'a> {}
+impl<'a> Iterator for PropertyTables<'a> {
+    type Item = PropertyTable<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(index, json)| PropertyTable::new(self.document, index, json))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter
+            .nth(n)
+            .map(|(index, json)| PropertyTable::new(self.document, index, json))
+    }
+}
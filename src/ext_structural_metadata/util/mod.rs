@@ -0,0 +1,371 @@
+use crate::ext_structural_metadata::PropertyTable;
+use crate::Buffer;
+use byteorder::{ByteOrder, LE};
+use json::extensions::root::ext_structural_metadata::{
+    Class, ClassProperty, ComponentType, Enum, OffsetType, Type,
+};
+use json::validation::Checked;
+
+/// Property table reader.
+#[derive(Clone, Debug)]
+pub struct Reader<'a, 's, F>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    pub(crate) table: PropertyTable<'a>,
+    pub(crate) get_buffer_data: F,
+}
+
+/// A decoded property value.
+///
+/// Floating point and normalized integer components have the property offset
+/// and scale applied; other integers are returned as stored.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value<'a> {
+    /// A boolean value.
+    Boolean(bool),
+
+    /// A signed integer component.
+    Int(i64),
+
+    /// An unsigned integer component.
+    UInt(u64),
+
+    /// A floating point or normalized integer component.
+    Float(f64),
+
+    /// A UTF-8 string.
+    String(String),
+
+    /// An enum value and the name it maps to in the schema.
+    Enum {
+        /// The stored integer value.
+        value: i64,
+
+        /// The name of the value, if declared by the enum.
+        name: Option<&'a str>,
+    },
+
+    /// The components of a vector or column-major matrix.
+    Vector(Vec<Value<'a>>),
+
+    /// The elements of an array property.
+    Array(Vec<Value<'a>>),
+}
+
+/// An `Iterator` that decodes the rows of a property table column.
+#[derive(Clone, Debug)]
+pub struct Column<'a, 's> {
+    /// The class property describing the column.
+    property: &'a ClassProperty,
+
+    /// The element type.
+    type_: Type,
+
+    /// The numeric component type, or enum value type.
+    component_type: Option<ComponentType>,
+
+    /// The enum of an enum property.
+    enumeration: Option<&'a Enum>,
+
+    /// The number of rows.
+    count: usize,
+
+    /// The values buffer view data.
+    values: &'s [u8],
+
+    /// The array offsets buffer view data.
+    array_offsets: Option<Offsets<'s>>,
+
+    /// The string offsets buffer view data.
+    string_offsets: Option<Offsets<'s>>,
+
+    /// The offset applied to transformable components.
+    offset: Option<&'a json::Value>,
+
+    /// The scale applied to transformable components.
+    scale: Option<&'a json::Value>,
+
+    /// The next row visited by the iterator.
+    row: usize,
+}
+
+/// Offsets into another buffer view.
+#[derive(Clone, Copy, Debug)]
+struct Offsets<'s> {
+    data: &'s [u8],
+    type_: OffsetType,
+}
+
+impl<'a, 's, F> Reader<'a, 's, F>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    /// Returns the class describing the table, if the schema is embedded.
+    pub fn class(&self) -> Option<&'a Class> {
+        self.table.class()
+    }
+
+    /// Visits the values of the property with the given identifier.
+    ///
+    /// Returns `None` if the property is not stored in the table, is not
+    /// declared by the class, or its buffer views are unavailable.  The class
+    /// is only known for embedded schemas, so this always returns `None` when
+    /// the schema is referenced through `schemaUri`.
+    pub fn read_property(&self, id: &str) -> Option<Column<'a, 's>> {
+        let json = self.table.json.properties.get(id)?;
+        let property = self.class()?.properties.get(id)?;
+        let type_ = checked(property.type_)?;
+        let enumeration = match type_ {
+            Type::Enum => Some(
+                self.table
+                    .document
+                    .structural_metadata()?
+                    .enumeration(property.enum_type.as_deref()?)?,
+            ),
+            _ => None,
+        };
+        let component_type = match type_ {
+            Type::Enum => Some(checked(enumeration?.value_type)?),
+            Type::String | Type::Boolean => None,
+            _ => Some(checked(property.component_type?)?),
+        };
+        let array_offsets = match json.array_offsets {
+            Some(index) => Some(Offsets {
+                data: self.view_data(index)?,
+                type_: checked(json.array_offset_type)?,
+            }),
+            None => None,
+        };
+        let string_offsets = match json.string_offsets {
+            Some(index) => Some(Offsets {
+                data: self.view_data(index)?,
+                type_: checked(json.string_offset_type)?,
+            }),
+            None => None,
+        };
+        Some(Column {
+            property,
+            type_,
+            component_type,
+            enumeration,
+            count: self.table.count(),
+            values: self.view_data(json.values)?,
+            array_offsets,
+            string_offsets,
+            offset: json.offset.as_ref().or(property.offset.as_ref()),
+            scale: json.scale.as_ref().or(property.scale.as_ref()),
+            row: 0,
+        })
+    }
+
+    fn view_data(&self, index: json::Index<json::buffer::View>) -> Option<&'s [u8]> {
+        let view = self.table.document.views().nth(index.value())?;
+        let data = (self.get_buffer_data)(view.buffer())?;
+        data.get(view.offset()..view.offset() + view.length())
+    }
+}
+
+impl<'a, 's> Column<'a, 's> {
+    /// Returns the class property describing the column.
+    pub fn property(&self) -> &'a ClassProperty {
+        self.property
+    }
+
+    /// Decodes the value of the given row.
+    ///
+    /// Returns `None` if the row is out of range or the buffer views are too
+    /// short to contain it.
+    pub fn get(&self, row: usize) -> Option<Value<'a>> {
+        if row >= self.count {
+            return None;
+        }
+        if !self.property.array {
+            return self.element(row, None);
+        }
+
+        let (start, len) = match self.property.count {
+            Some(count) => (row * count as usize, count as usize),
+            None => {
+                let offsets = self.array_offsets?;
+                let begin = offsets.get(row)?;
+                let end = offsets.get(row + 1)?;
+                // Boolean arrays are offset in bits and string arrays by
+                // string offset, everything else in bytes.
+                let unit = match self.type_ {
+                    Type::Boolean => 1,
+                    Type::String => self.string_offsets?.type_.size(),
+                    _ => self.element_size(),
+                };
+                (begin / unit, end.checked_sub(begin)? / unit)
+            }
+        };
+        (0..len)
+            .map(|i| self.element(start + i, Some(i)))
+            .collect::<Option<Vec<_>>>()
+            .map(Value::Array)
+    }
+
+    /// Returns the size in bytes of a numeric or enum element.
+    fn element_size(&self) -> usize {
+        self.component_type.map_or(1, |ty| ty.size()) * self.type_.multiplicity()
+    }
+
+    /// Decodes the element with the given index in the flattened values.
+    fn element(&self, index: usize, array_index: Option<usize>) -> Option<Value<'a>> {
+        match self.type_ {
+            Type::Boolean => {
+                let byte = self.values.get(index / 8)?;
+                Some(Value::Boolean((byte >> (index % 8)) & 1 == 1))
+            }
+            Type::String => {
+                let offsets = self.string_offsets?;
+                let bytes = self
+                    .values
+                    .get(offsets.get(index)?..offsets.get(index + 1)?)?;
+                std::str::from_utf8(bytes)
+                    .ok()
+                    .map(|string| Value::String(string.to_owned()))
+            }
+            Type::Enum => {
+                let component_type = self.component_type?;
+                let value = match read(self.values, index, component_type)? {
+                    Value::Int(value) => value,
+                    Value::UInt(value) => value as i64,
+                    _ => return None,
+                };
+                let name = self
+                    .enumeration?
+                    .values
+                    .iter()
+                    .find(|enum_value| enum_value.value == value)
+                    .map(|enum_value| enum_value.name.as_str());
+                Some(Value::Enum { value, name })
+            }
+            _ => {
+                let multiplicity = self.type_.multiplicity();
+                if multiplicity == 1 {
+                    return self.component(index, &array_index.into_iter().collect::<Vec<_>>());
+                }
+                (0..multiplicity)
+                    .map(|component| {
+                        let indices = array_index
+                            .into_iter()
+                            .chain(Some(component))
+                            .collect::<Vec<_>>();
+                        self.component(index * multiplicity + component, &indices)
+                    })
+                    .collect::<Option<Vec<_>>>()
+                    .map(Value::Vector)
+            }
+        }
+    }
+
+    /// Decodes a numeric component, applying normalization, scale and offset
+    /// where the property allows it. `indices` locates the matching entry of
+    /// array-valued scale and offset transforms.
+    fn component(&self, index: usize, indices: &[usize]) -> Option<Value<'a>> {
+        let component_type = self.component_type?;
+        let mut x = match read(self.values, index, component_type)? {
+            Value::Float(x) => x,
+            Value::Int(x) if self.property.normalized => {
+                (x as f64 / signed_max(component_type) as f64).max(-1.0)
+            }
+            Value::UInt(x) if self.property.normalized => {
+                x as f64 / unsigned_max(component_type) as f64
+            }
+            value => return Some(value),
+        };
+        if let Some(scale) = self.scale.and_then(|scale| transform(scale, indices)) {
+            x *= scale;
+        }
+        if let Some(offset) = self.offset.and_then(|offset| transform(offset, indices)) {
+            x += offset;
+        }
+        Some(Value::Float(x))
+    }
+}
+
+impl<'a, 's> ExactSizeIterator for Column<'a, 's> {}
+impl<'a, 's> Iterator for Column<'a, 's> {
+    type Item = Value<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.get(self.row)?;
+        self.row += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint = self.count.saturating_sub(self.row);
+        (hint, Some(hint))
+    }
+}
+
+impl<'s> Offsets<'s> {
+    fn get(&self, index: usize) -> Option<usize> {
+        let size = self.type_.size();
+        let bytes = self.data.get(index * size..(index + 1) * size)?;
+        Some(match self.type_ {
+            OffsetType::U8 => bytes[0] as usize,
+            OffsetType::U16 => LE::read_u16(bytes) as usize,
+            OffsetType::U32 => LE::read_u32(bytes) as usize,
+            OffsetType::U64 => LE::read_u64(bytes) as usize,
+        })
+    }
+}
+
+fn checked<T: Copy>(value: Checked<T>) -> Option<T> {
+    match value {
+        Checked::Valid(value) => Some(value),
+        Checked::Invalid => None,
+    }
+}
+
+/// Reads the component with the given index from tightly packed data.
+fn read<'a>(data: &[u8], index: usize, component_type: ComponentType) -> Option<Value<'a>> {
+    let size = component_type.size();
+    let bytes = data.get(index * size..(index + 1) * size)?;
+    Some(match component_type {
+        ComponentType::I8 => Value::Int(bytes[0] as i8 as i64),
+        ComponentType::U8 => Value::UInt(bytes[0] as u64),
+        ComponentType::I16 => Value::Int(LE::read_i16(bytes) as i64),
+        ComponentType::U16 => Value::UInt(LE::read_u16(bytes) as u64),
+        ComponentType::I32 => Value::Int(LE::read_i32(bytes) as i64),
+        ComponentType::U32 => Value::UInt(LE::read_u32(bytes) as u64),
+        ComponentType::I64 => Value::Int(LE::read_i64(bytes)),
+        ComponentType::U64 => Value::UInt(LE::read_u64(bytes)),
+        ComponentType::F32 => Value::Float(LE::read_f32(bytes) as f64),
+        ComponentType::F64 => Value::Float(LE::read_f64(bytes)),
+    })
+}
+
+fn signed_max(component_type: ComponentType) -> i64 {
+    match component_type {
+        ComponentType::I8 => i8::MAX as i64,
+        ComponentType::I16 => i16::MAX as i64,
+        ComponentType::I32 => i32::MAX as i64,
+        _ => i64::MAX,
+    }
+}
+
+fn unsigned_max(component_type: ComponentType) -> u64 {
+    match component_type {
+        ComponentType::U8 => u8::MAX as u64,
+        ComponentType::U16 => u16::MAX as u64,
+        ComponentType::U32 => u32::MAX as u64,
+        _ => u64::MAX,
+    }
+}
+
+/// Looks up the scale or offset of a component. Transforms of arrays,
+/// vectors and matrices are nested JSON arrays indexed by `indices`.
+fn transform(value: &json::Value, indices: &[usize]) -> Option<f64> {
+    match value {
+        json::Value::Array(items) => {
+            let (first, rest) = indices.split_first()?;
+            transform(items.get(*first)?, rest)
+        }
+        _ => value.as_f64(),
+    }
+}
@@ -132,6 +132,15 @@ impl<'a> Image<'a> {
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
     }
+
+    /// Returns the XMP metadata packet of this image as defined by the
+    /// `KHR_xmp_json_ld` extension.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_xmp_json_ld")))]
+    pub fn xmp_packet(&self) -> Option<crate::khr_xmp_json_ld::Packet<'a>> {
+        self.document
+            .xmp_packet(self.json.extensions.as_ref()?.khr_xmp_json_ld.as_ref())
+    }
 }
 
 #[cfg(feature = "import")]
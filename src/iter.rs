@@ -80,6 +80,31 @@ pub struct Images<'a> {
     pub(crate) document: &'a Document,
 }
 
+/// An `Iterator` that visits every XMP metadata packet in a glTF asset.
+#[cfg(feature = "KHR_xmp_json_ld")]
+#[derive(Clone, Debug)]
+pub struct XmpPackets<'a> {
+    /// Internal packet iterator.
+    pub(crate) iter:
+        iter::Enumerate<slice::Iter<'a, json::extensions::root::khr_xmp_json_ld::Packet>>,
+
+    /// The internal root glTF object.
+    pub(crate) document: &'a Document,
+}
+
+/// An `Iterator` that visits every image based light in a glTF asset.
+#[cfg(feature = "EXT_lights_image_based")]
+#[derive(Clone, Debug)]
+pub struct ImageBasedLights<'a> {
+    /// Internal light iterator.
+    pub(crate) iter: iter::Enumerate<
+        slice::Iter<'a, json::extensions::scene::ext_lights_image_based::ImageBasedLight>,
+    >,
+
+    /// The internal root glTF object.
+    pub(crate) document: &'a Document,
+}
+
 /// An `Iterator` that visits every light in a glTF asset.
 #[cfg(feature = "KHR_lights_punctual")]
 #[derive(Clone, Debug)]
@@ -376,6 +401,66 @@ impl<'a> Iterator for Images<'a> {
     }
 }
 
+#[cfg(feature = "EXT_lights_image_based")]
+impl<'a> ExactSizeIterator for ImageBasedLights<'a> {}
+
+#[cfg(feature = "EXT_lights_image_based")]
+impl<'a> Iterator for ImageBasedLights<'a> {
+    type Item = crate::ext_lights_image_based::ImageBasedLight<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(index, json)| {
+            crate::ext_lights_image_based::ImageBasedLight::new(self.document, index, json)
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+    fn last(mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter.next_back().map(|(index, json)| {
+            crate::ext_lights_image_based::ImageBasedLight::new(document, index, json)
+        })
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(|(index, json)| {
+            crate::ext_lights_image_based::ImageBasedLight::new(self.document, index, json)
+        })
+    }
+}
+
+#[cfg(feature = "KHR_xmp_json_ld")]
+impl<'a> ExactSizeIterator for XmpPackets<'a> {}
+
+#[cfg(feature = "KHR_xmp_json_ld")]
+impl<'a> Iterator for XmpPackets<'a> {
+    type Item = crate::khr_xmp_json_ld::Packet<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(index, json)| crate::khr_xmp_json_ld::Packet::new(self.document, index, json))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+    fn last(mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter
+            .next_back()
+            .map(|(index, json)| crate::khr_xmp_json_ld::Packet::new(document, index, json))
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter
+            .nth(n)
+            .map(|(index, json)| crate::khr_xmp_json_ld::Packet::new(self.document, index, json))
+    }
+}
+
 #[cfg(feature = "KHR_lights_punctual")]
 impl<'a> ExactSizeIterator for Lights<'a> {}
 
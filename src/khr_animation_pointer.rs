@@ -0,0 +1,61 @@
+use crate::{Camera, Document, Material, Mesh, Node};
+
+pub use json::extensions::animation::khr_animation_pointer::{
+    CameraProperty, LightProperty, MaterialProperty, NodeProperty,
+};
+
+/// The object and property targeted by an animation channel.
+#[derive(Clone, Debug)]
+pub enum Pointer<'a> {
+    /// A property of a node.
+    Node(Node<'a>, NodeProperty),
+
+    /// The default morph target weights of a mesh.
+    MeshWeights(Mesh<'a>),
+
+    /// A property of a material.
+    Material(Material<'a>, MaterialProperty),
+
+    /// A property of a camera.
+    Camera(Camera<'a>, CameraProperty),
+
+    /// A property of a `KHR_lights_punctual` light.
+    #[cfg(feature = "KHR_lights_punctual")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_lights_punctual")))]
+    Light(crate::khr_lights_punctual::Light<'a>, LightProperty),
+
+    /// The raw JSON pointer to a property that is not modeled by this crate.
+    Other(&'a str),
+}
+
+impl<'a> Pointer<'a> {
+    /// Resolves a JSON pointer against the document.
+    pub(crate) fn new(document: &'a Document, pointer: &'a str) -> Self {
+        use json::extensions::animation::khr_animation_pointer::Pointer as Json;
+        let resolved = match Json::parse(pointer) {
+            Some(Json::Node(index, property)) => document
+                .nodes()
+                .nth(index.value())
+                .map(|node| Pointer::Node(node, property)),
+            Some(Json::MeshWeights(index)) => document
+                .meshes()
+                .nth(index.value())
+                .map(Pointer::MeshWeights),
+            Some(Json::Material(index, property)) => document
+                .materials()
+                .nth(index.value())
+                .map(|material| Pointer::Material(material, property)),
+            Some(Json::Camera(index, property)) => document
+                .cameras()
+                .nth(index.value())
+                .map(|camera| Pointer::Camera(camera, property)),
+            #[cfg(feature = "KHR_lights_punctual")]
+            Some(Json::Light(index, property)) => document
+                .lights()
+                .and_then(|mut lights| lights.nth(index.value()))
+                .map(|light| Pointer::Light(light, property)),
+            Some(Json::Other(_)) | None => None,
+        };
+        resolved.unwrap_or(Pointer::Other(pointer))
+    }
+}
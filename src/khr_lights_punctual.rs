@@ -1,7 +1,8 @@
-use crate::Document;
+use crate::{Document, Node};
 use gltf_json::Extras;
 
 /// A light in the scene.
+#[derive(Clone, Debug)]
 pub struct Light<'a> {
     /// The parent `Document` struct.
     #[allow(dead_code)]
@@ -78,6 +79,76 @@ impl<'a> Light<'a> {
     }
 }
 
+/// A light attached to a node of a scene, placed in world space.
+#[derive(Clone, Debug)]
+pub struct LightInstance<'a> {
+    /// The node the light is attached to.
+    node: Node<'a>,
+
+    /// The light referenced by the node.
+    light: Light<'a>,
+
+    /// The column-major world transform of the node.
+    transform: [[f32; 4]; 4],
+}
+
+/// An `Iterator` that visits every light instance of a scene.
+#[derive(Clone, Debug)]
+pub struct LightInstances<'a> {
+    /// The traversal of the scene nodes.
+    pub(crate) traverse: crate::scene::iter::Traverse<'a>,
+}
+
+impl<'a> LightInstance<'a> {
+    /// Returns the node the light is attached to.
+    pub fn node(&self) -> Node<'a> {
+        self.node.clone()
+    }
+
+    /// Returns the light referenced by the node.
+    pub fn light(&self) -> Light<'a> {
+        self.light.clone()
+    }
+
+    /// Returns the column-major world transform of the node.
+    pub fn transform(&self) -> [[f32; 4]; 4] {
+        self.transform
+    }
+
+    /// Returns the world-space position of the light.
+    pub fn position(&self) -> [f32; 3] {
+        let [x, y, z, _] = self.transform[3];
+        [x, y, z]
+    }
+
+    /// Returns the normalized world-space direction of the light, which is
+    /// the local -Z axis of the node.
+    pub fn direction(&self) -> [f32; 3] {
+        let [x, y, z, _] = self.transform[2];
+        let length = (x * x + y * y + z * z).sqrt();
+        if length == 0.0 {
+            [0.0, 0.0, -1.0]
+        } else {
+            [-x / length, -y / length, -z / length]
+        }
+    }
+}
+
+impl<'a> Iterator for LightInstances<'a> {
+    type Item = LightInstance<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.traverse.by_ref().find_map(|world_node| {
+            let light = world_node.node.light()?;
+            Some(LightInstance {
+                node: world_node.node,
+                light,
+                transform: world_node.matrix,
+            })
+        })
+    }
+}
+
 /// Light subcategory.
 pub enum Kind {
     /// Directional lights are light sources that act as though they are infinitely far away
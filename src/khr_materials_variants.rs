@@ -1,13 +1,13 @@
-use crate::{Document, Material};
+use crate::{Document, Material, Primitive};
 
 /// A variant.
+#[derive(Clone, Debug)]
 pub struct Variant<'a> {
     /// The parent `Document` struct.
     #[allow(dead_code)]
     document: &'a Document,
 
     /// The corresponding JSON index.
-    #[allow(dead_code)]
     index: usize,
 
     /// The corresponding JSON struct.
@@ -28,13 +28,33 @@ impl<'a> Variant<'a> {
         }
     }
 
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
     /// Name of the variant.
     pub fn name(&self) -> &'a str {
         &self.json.name
     }
+
+    /// Returns every primitive whose material is overridden by this variant,
+    /// paired with the material it uses when the variant is active.
+    pub fn primitive_materials(&self) -> Vec<(Primitive<'a>, Material<'a>)> {
+        let mut pairs = Vec::new();
+        for mesh in self.document.meshes() {
+            for primitive in mesh.primitives() {
+                if let Some(mapping) = primitive.mapping_for_variant(self.index) {
+                    pairs.push((primitive, mapping.material()));
+                }
+            }
+        }
+        pairs
+    }
 }
 
 /// A mapping.
+#[derive(Clone, Debug)]
 pub struct Mapping<'a> {
     /// The parent `Document` struct.
     document: &'a Document,
@@ -0,0 +1,51 @@
+use crate::Document;
+use json::{Map, Value};
+
+/// An XMP metadata packet serialized as JSON-LD.
+#[derive(Clone, Debug)]
+pub struct Packet<'a> {
+    /// The parent `Document` struct.
+    #[allow(dead_code)]
+    document: &'a Document,
+
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::khr_xmp_json_ld::Packet,
+}
+
+impl<'a> Packet<'a> {
+    /// Constructs a `Packet`.
+    pub(crate) fn new(
+        document: &'a Document,
+        index: usize,
+        json: &'a json::extensions::root::khr_xmp_json_ld::Packet,
+    ) -> Self {
+        Self {
+            document,
+            index,
+            json,
+        }
+    }
+
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the JSON-LD object of this packet, including its `@context`.
+    pub fn json_ld(&self) -> &'a Map<String, Value> {
+        &self.json.0
+    }
+
+    /// Returns the JSON-LD `@context` mapping namespace prefixes to IRIs.
+    pub fn context(&self) -> Option<&'a Map<String, Value>> {
+        self.json.0.get("@context")?.as_object()
+    }
+
+    /// Returns the value of a metadata property, such as `dc:title`.
+    pub fn get(&self, property: &str) -> Option<&'a Value> {
+        self.json.0.get(property)
+    }
+}
@@ -106,6 +106,26 @@ pub mod camera;
 /// Images that may be used by textures.
 pub mod image;
 
+/// Support for the `EXT_lights_image_based` extension.
+#[cfg(feature = "EXT_lights_image_based")]
+#[cfg_attr(docsrs, doc(cfg(feature = "EXT_lights_image_based")))]
+pub mod ext_lights_image_based;
+
+/// Support for the `EXT_mesh_features` extension.
+#[cfg(feature = "EXT_mesh_features")]
+#[cfg_attr(docsrs, doc(cfg(feature = "EXT_mesh_features")))]
+pub mod ext_mesh_features;
+
+/// Support for the `EXT_mesh_gpu_instancing` extension.
+#[cfg(feature = "EXT_mesh_gpu_instancing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "EXT_mesh_gpu_instancing")))]
+pub mod ext_mesh_gpu_instancing;
+
+/// Support for the `EXT_structural_metadata` extension.
+#[cfg(feature = "EXT_structural_metadata")]
+#[cfg_attr(docsrs, doc(cfg(feature = "EXT_structural_metadata")))]
+pub mod ext_structural_metadata;
+
 /// The reference importer.
 #[cfg(feature = "import")]
 #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
@@ -114,6 +134,11 @@ mod import;
 /// Iterators for walking the glTF node hierarchy.
 pub mod iter;
 
+/// Support for the `KHR_animation_pointer` extension.
+#[cfg(feature = "KHR_animation_pointer")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_animation_pointer")))]
+pub mod khr_animation_pointer;
+
 /// Support for the `KHR_lights_punctual` extension.
 #[cfg(feature = "KHR_lights_punctual")]
 #[cfg_attr(docsrs, doc(cfg(feature = "KHR_lights_punctual")))]
@@ -124,12 +149,26 @@ pub mod khr_lights_punctual;
 #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_variants")))]
 pub mod khr_materials_variants;
 
+/// Support for the `KHR_xmp_json_ld` extension.
+#[cfg(feature = "KHR_xmp_json_ld")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_xmp_json_ld")))]
+pub mod khr_xmp_json_ld;
+
 /// Material properties of primitives.
 pub mod material;
 
 /// For internal use.
 mod math;
 
+/// Name lookup tables.
+#[cfg(feature = "names")]
+mod names;
+
+/// Support for the `MSFT_lod` extension.
+#[cfg(feature = "MSFT_lod")]
+#[cfg_attr(docsrs, doc(cfg(feature = "MSFT_lod")))]
+pub mod msft_lod;
+
 /// Meshes and their primitives.
 pub mod mesh;
 
@@ -172,7 +211,7 @@ pub use self::skin::Skin;
 pub use self::texture::Texture;
 
 use std::path::Path;
-use std::{fs, io, ops, result};
+use std::{fmt, fs, io, ops, result, sync};
 
 pub(crate) trait Normalize<T> {
     fn normalize(self) -> T;
@@ -257,8 +296,58 @@ pub struct Gltf {
 }
 
 /// glTF JSON wrapper.
+///
+/// Lookup tables for the node hierarchy and for names are built the first
+/// time they are needed, and are shared by clones of the document.
 #[derive(Clone, Debug)]
-pub struct Document(json::Root);
+pub struct Document(json::Root, Indices);
+
+/// Lookup tables derived from the JSON, built on first use.
+#[derive(Clone, Debug, Default)]
+struct Indices {
+    /// Parent links of the node hierarchy.
+    hierarchy: Lazy<scene::hierarchy::Hierarchy>,
+
+    /// Top-level objects by name.
+    #[cfg(feature = "names")]
+    names: Lazy<names::Names>,
+}
+
+/// A value built on first use.
+///
+/// This stands in for `std::sync::OnceLock`, which requires a newer compiler.
+struct Lazy<T>(sync::Mutex<Option<sync::Arc<T>>>);
+
+impl<T> Lazy<T> {
+    /// Returns the value, building it with `init` if this is the first use.
+    fn get_or_init(&self, init: impl FnOnce() -> T) -> sync::Arc<T> {
+        let mut value = self.lock();
+        value.get_or_insert_with(|| sync::Arc::new(init())).clone()
+    }
+
+    fn lock(&self) -> sync::MutexGuard<'_, Option<sync::Arc<T>>> {
+        // The value is only ever set once, so a panic cannot leave it torn.
+        self.0.lock().unwrap_or_else(sync::PoisonError::into_inner)
+    }
+}
+
+impl<T> Clone for Lazy<T> {
+    fn clone(&self) -> Self {
+        Lazy(sync::Mutex::new(self.lock().clone()))
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Lazy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Lazy").field(&*self.lock()).finish()
+    }
+}
+
+impl<T> Default for Lazy<T> {
+    fn default() -> Self {
+        Lazy(sync::Mutex::new(None))
+    }
+}
 
 impl Gltf {
     /// Convenience function that loads glTF from the file system.
@@ -352,7 +441,7 @@ impl Document {
     /// Loads glTF from pre-deserialized JSON without performing
     /// validation checks.
     pub fn from_json_without_validation(json: json::Root) -> Self {
-        Document(json)
+        Document(json, Indices::default())
     }
 
     /// Unwraps the glTF document.
@@ -360,6 +449,19 @@ impl Document {
         self.0
     }
 
+    /// Returns the node hierarchy, building it on first use.
+    pub(crate) fn hierarchy(&self) -> sync::Arc<scene::hierarchy::Hierarchy> {
+        self.1
+            .hierarchy
+            .get_or_init(|| scene::hierarchy::Hierarchy::new(&self.0))
+    }
+
+    /// Returns the name lookup tables, building them on first use.
+    #[cfg(feature = "names")]
+    fn names(&self) -> sync::Arc<names::Names> {
+        self.1.names.get_or_init(|| names::Names::new(&self.0))
+    }
+
     /// Perform validation checks on loaded glTF.
     pub(crate) fn validate(&self) -> Result<()> {
         use json::validation::Validate;
@@ -375,6 +477,14 @@ impl Document {
         }
     }
 
+    /// Returns the first accessor named `name`.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn accessor_by_name(&self, name: &str) -> Option<Accessor<'_>> {
+        let index = self.names().accessors.get(name)?;
+        self.accessors().nth(index)
+    }
+
     /// Returns an `Iterator` that visits the accessors of the glTF asset.
     pub fn accessors(&self) -> iter::Accessors {
         iter::Accessors {
@@ -383,6 +493,14 @@ impl Document {
         }
     }
 
+    /// Returns the first animation named `name`.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn animation_by_name(&self, name: &str) -> Option<Animation<'_>> {
+        let index = self.names().animations.get(name)?;
+        self.animations().nth(index)
+    }
+
     /// Returns an `Iterator` that visits the animations of the glTF asset.
     pub fn animations(&self) -> iter::Animations {
         iter::Animations {
@@ -391,6 +509,14 @@ impl Document {
         }
     }
 
+    /// Returns the first buffer named `name`.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn buffer_by_name(&self, name: &str) -> Option<Buffer<'_>> {
+        let index = self.names().buffers.get(name)?;
+        self.buffers().nth(index)
+    }
+
     /// Returns an `Iterator` that visits the pre-loaded buffers of the glTF asset.
     pub fn buffers(&self) -> iter::Buffers {
         iter::Buffers {
@@ -399,6 +525,14 @@ impl Document {
         }
     }
 
+    /// Returns the first camera named `name`.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn camera_by_name(&self, name: &str) -> Option<Camera<'_>> {
+        let index = self.names().cameras.get(name)?;
+        self.cameras().nth(index)
+    }
+
     /// Returns an `Iterator` that visits the cameras of the glTF asset.
     pub fn cameras(&self) -> iter::Cameras {
         iter::Cameras {
@@ -407,6 +541,17 @@ impl Document {
         }
     }
 
+    /// Returns every name shared by several top-level objects of the same
+    /// kind, with the paths of those objects, such as `meshes[0]`.
+    ///
+    /// Names need not be unique, so the `*_by_name` methods return the first
+    /// of these objects.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn duplicate_names(&self) -> Vec<(&str, Vec<json::Path>)> {
+        self.names().duplicates(&self.0)
+    }
+
     /// Returns the default scene, if provided.
     pub fn default_scene(&self) -> Option<Scene> {
         self.0
@@ -425,6 +570,14 @@ impl Document {
         iter::ExtensionsRequired(self.0.extensions_required.iter())
     }
 
+    /// Returns the first image named `name`.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn image_by_name(&self, name: &str) -> Option<Image<'_>> {
+        let index = self.names().images.get(name)?;
+        self.images().nth(index)
+    }
+
     /// Returns an `Iterator` that visits the pre-loaded images of the glTF asset.
     pub fn images(&self) -> iter::Images {
         iter::Images {
@@ -433,6 +586,81 @@ impl Document {
         }
     }
 
+    /// Returns an `Iterator` that visits the image based lights of the glTF asset as
+    /// defined by the `EXT_lights_image_based` extension.
+    #[cfg(feature = "EXT_lights_image_based")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_lights_image_based")))]
+    pub fn image_based_lights(&self) -> Option<iter::ImageBasedLights<'_>> {
+        let iter = self
+            .0
+            .extensions
+            .as_ref()?
+            .ext_lights_image_based
+            .as_ref()?
+            .lights
+            .iter()
+            .enumerate();
+
+        Some(iter::ImageBasedLights {
+            iter,
+            document: self,
+        })
+    }
+
+    /// Returns the metadata schema and property storage of the glTF asset as
+    /// defined by the `EXT_structural_metadata` extension.
+    #[cfg(feature = "EXT_structural_metadata")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_structural_metadata")))]
+    pub fn structural_metadata(&self) -> Option<ext_structural_metadata::StructuralMetadata<'_>> {
+        let json = self
+            .0
+            .extensions
+            .as_ref()?
+            .ext_structural_metadata
+            .as_ref()?;
+        Some(ext_structural_metadata::StructuralMetadata::new(self, json))
+    }
+
+    /// Returns an `Iterator` that visits the XMP metadata packets of the glTF
+    /// asset as defined by the `KHR_xmp_json_ld` extension.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_xmp_json_ld")))]
+    pub fn xmp_packets(&self) -> Option<iter::XmpPackets<'_>> {
+        let iter = self
+            .0
+            .extensions
+            .as_ref()?
+            .khr_xmp_json_ld
+            .as_ref()?
+            .packets
+            .iter()
+            .enumerate();
+
+        Some(iter::XmpPackets {
+            iter,
+            document: self,
+        })
+    }
+
+    /// Returns the XMP metadata packet associated with the asset as a whole.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_xmp_json_ld")))]
+    pub fn asset_xmp_packet(&self) -> Option<khr_xmp_json_ld::Packet<'_>> {
+        self.xmp_packet(self.0.asset.extensions.as_ref()?.khr_xmp_json_ld.as_ref())
+    }
+
+    /// Resolves a reference to an XMP metadata packet.
+    ///
+    /// Returns `None` if there is no reference, or the packet it references
+    /// is not defined by the document.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    pub(crate) fn xmp_packet(
+        &self,
+        reference: Option<&json::extensions::root::khr_xmp_json_ld::PacketReference>,
+    ) -> Option<khr_xmp_json_ld::Packet<'_>> {
+        self.xmp_packets()?.nth(reference?.packet.value())
+    }
+
     /// Returns an `Iterator` that visits the lights of the glTF asset as defined by the
     /// `KHR_lights_punctual` extension.
     #[cfg(feature = "KHR_lights_punctual")]
@@ -475,6 +703,22 @@ impl Document {
         })
     }
 
+    /// Returns the variant with the given name as defined by the
+    /// `KHR_materials_variants` extension.
+    #[cfg(feature = "KHR_materials_variants")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_variants")))]
+    pub fn variant_by_name(&self, name: &str) -> Option<khr_materials_variants::Variant<'_>> {
+        self.variants()?.find(|variant| variant.name() == name)
+    }
+
+    /// Returns the first material named `name`.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn material_by_name(&self, name: &str) -> Option<Material<'_>> {
+        let index = self.names().materials.get(name)?;
+        self.materials().nth(index)
+    }
+
     /// Returns an `Iterator` that visits the materials of the glTF asset.
     pub fn materials(&self) -> iter::Materials {
         iter::Materials {
@@ -483,6 +727,14 @@ impl Document {
         }
     }
 
+    /// Returns the first mesh named `name`.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn mesh_by_name(&self, name: &str) -> Option<Mesh<'_>> {
+        let index = self.names().meshes.get(name)?;
+        self.meshes().nth(index)
+    }
+
     /// Returns an `Iterator` that visits the meshes of the glTF asset.
     pub fn meshes(&self) -> iter::Meshes {
         iter::Meshes {
@@ -491,6 +743,25 @@ impl Document {
         }
     }
 
+    /// Returns the node at the end of a slash-separated path of node names,
+    /// such as `Root/Arm/Hand`, starting from a node without a parent.
+    ///
+    /// When several nodes match, the first one found depth-first is returned.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn node_by_path(&self, path: &str) -> Option<Node<'_>> {
+        let roots = self.nodes().filter(|node| node.parent().is_none());
+        scene::find_path(roots, &path.split('/').collect::<Vec<_>>())
+    }
+
+    /// Returns the first node named `name`.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn node_by_name(&self, name: &str) -> Option<Node<'_>> {
+        let index = self.names().nodes.get(name)?;
+        self.nodes().nth(index)
+    }
+
     /// Returns an `Iterator` that visits the nodes of the glTF asset.
     pub fn nodes(&self) -> iter::Nodes {
         iter::Nodes {
@@ -499,6 +770,14 @@ impl Document {
         }
     }
 
+    /// Returns the first sampler named `name`.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn sampler_by_name(&self, name: &str) -> Option<texture::Sampler<'_>> {
+        let index = self.names().samplers.get(name)?;
+        self.samplers().nth(index)
+    }
+
     /// Returns an `Iterator` that visits the samplers of the glTF asset.
     pub fn samplers(&self) -> iter::Samplers {
         iter::Samplers {
@@ -507,6 +786,14 @@ impl Document {
         }
     }
 
+    /// Returns the first scene named `name`.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn scene_by_name(&self, name: &str) -> Option<Scene<'_>> {
+        let index = self.names().scenes.get(name)?;
+        self.scenes().nth(index)
+    }
+
     /// Returns an `Iterator` that visits the scenes of the glTF asset.
     pub fn scenes(&self) -> iter::Scenes {
         iter::Scenes {
@@ -515,6 +802,20 @@ impl Document {
         }
     }
 
+    /// Returns the first scene containing the given node, if any.
+    pub fn scene_of(&self, node: &Node) -> Option<Scene<'_>> {
+        let index = self.hierarchy().scenes[node.index()]?;
+        self.scenes().nth(index)
+    }
+
+    /// Returns the first skin named `name`.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn skin_by_name(&self, name: &str) -> Option<Skin<'_>> {
+        let index = self.names().skins.get(name)?;
+        self.skins().nth(index)
+    }
+
     /// Returns an `Iterator` that visits the skins of the glTF asset.
     pub fn skins(&self) -> iter::Skins {
         iter::Skins {
@@ -523,6 +824,14 @@ impl Document {
         }
     }
 
+    /// Returns the first texture named `name`.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn texture_by_name(&self, name: &str) -> Option<Texture<'_>> {
+        let index = self.names().textures.get(name)?;
+        self.textures().nth(index)
+    }
+
     /// Returns an `Iterator` that visits the textures of the glTF asset.
     pub fn textures(&self) -> iter::Textures {
         iter::Textures {
@@ -531,6 +840,14 @@ impl Document {
         }
     }
 
+    /// Returns the first buffer view named `name`.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn view_by_name(&self, name: &str) -> Option<buffer::View<'_>> {
+        let index = self.names().buffer_views.get(name)?;
+        self.views().nth(index)
+    }
+
     /// Returns an `Iterator` that visits the pre-loaded buffer views of the glTF
     /// asset.
     pub fn views(&self) -> iter::Views {
@@ -2,6 +2,11 @@ use crate::{texture, Document};
 
 pub use json::material::AlphaMode;
 
+/// Conversion of specular-glossiness materials to the metallic-roughness model.
+#[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_pbrSpecularGlossiness")))]
+pub mod spec_gloss;
+
 lazy_static! {
     static ref DEFAULT_MATERIAL: json::material::Material = Default::default();
 }
@@ -119,6 +124,26 @@ impl<'a> Material<'a> {
             .map(|x| Transmission::new(self.document, x))
     }
 
+    /// Returns the levels of detail of this material as defined by the
+    /// `MSFT_lod` extension, ordered from highest to lowest detail.
+    ///
+    /// The first level is always this material. Materials without the
+    /// extension have a single level.
+    #[cfg(feature = "MSFT_lod")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "MSFT_lod")))]
+    pub fn lod_chain(&self) -> Vec<crate::msft_lod::Level<Material<'a>>> {
+        let ids = self
+            .json
+            .extensions
+            .as_ref()
+            .and_then(|extensions| extensions.msft_lod.as_ref())
+            .map_or(&[][..], |lod| lod.ids.as_slice());
+        let materials = ids
+            .iter()
+            .map(|index| self.document.materials().nth(index.value()).unwrap());
+        crate::msft_lod::chain(self.clone(), materials, &self.json.extras)
+    }
+
     /// Parameter values that define the index of refraction of the material
     #[cfg(feature = "KHR_materials_ior")]
     #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_ior")))]
@@ -223,6 +248,15 @@ impl<'a> Material<'a> {
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
     }
+
+    /// Returns the XMP metadata packet of this material as defined by the
+    /// `KHR_xmp_json_ld` extension.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_xmp_json_ld")))]
+    pub fn xmp_packet(&self) -> Option<crate::khr_xmp_json_ld::Packet<'a>> {
+        self.document
+            .xmp_packet(self.json.extensions.as_ref()?.khr_xmp_json_ld.as_ref())
+    }
 }
 
 /// A set of parameter values that are used to define the metallic-roughness
@@ -559,6 +593,18 @@ impl<'a> NormalTexture<'a> {
         self.json.tex_coord
     }
 
+    /// Returns texture transform information
+    #[cfg(feature = "KHR_texture_transform")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_texture_transform")))]
+    pub fn texture_transform(&self) -> Option<texture::TextureTransform<'a>> {
+        self.json
+            .extensions
+            .as_ref()?
+            .texture_transform
+            .as_ref()
+            .map(texture::TextureTransform::new)
+    }
+
     /// Returns the referenced texture.
     pub fn texture(&self) -> texture::Texture<'a> {
         self.texture.clone()
@@ -598,6 +644,18 @@ impl<'a> OcclusionTexture<'a> {
         self.json.tex_coord
     }
 
+    /// Returns texture transform information
+    #[cfg(feature = "KHR_texture_transform")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_texture_transform")))]
+    pub fn texture_transform(&self) -> Option<texture::TextureTransform<'a>> {
+        self.json
+            .extensions
+            .as_ref()?
+            .texture_transform
+            .as_ref()
+            .map(texture::TextureTransform::new)
+    }
+
     /// Returns the referenced texture.
     pub fn texture(&self) -> texture::Texture<'a> {
         self.texture.clone()
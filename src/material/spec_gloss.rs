@@ -0,0 +1,287 @@
+use crate::material::{Material, PbrSpecularGlossiness};
+
+#[cfg(feature = "import")]
+use crate::image;
+
+/// Reflectance of dielectric materials at normal incidence.
+const DIELECTRIC_SPECULAR: f32 = 0.04;
+
+/// Guards divisions by the metallic factor and its complement.
+const EPSILON: f32 = 1e-6;
+
+/// Metallic-roughness parameters equivalent to specular-glossiness ones.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MetallicRoughness {
+    /// The linear RGBA base color.
+    pub base_color: [f32; 4],
+
+    /// The metalness of the material.
+    pub metallic: f32,
+
+    /// The roughness of the material.
+    pub roughness: f32,
+}
+
+/// Textures referenced by a material rewritten to the metallic-roughness model.
+///
+/// Baked textures already include the converted factors, so the rewritten
+/// material uses factors of `1.0` wherever a texture is provided.
+#[derive(Clone, Debug, Default)]
+pub struct Textures {
+    /// The baked base color texture.
+    pub base_color: Option<json::texture::Info>,
+
+    /// The baked metallic-roughness texture.
+    pub metallic_roughness: Option<json::texture::Info>,
+}
+
+/// Metallic-roughness textures baked from specular-glossiness images.
+#[cfg(feature = "import")]
+#[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+#[derive(Clone, Debug)]
+pub struct BakedTextures {
+    /// The sRGB base color image, with alpha taken from the diffuse image.
+    pub base_color: image::Data,
+
+    /// The linear metallic-roughness image, with roughness in the green
+    /// channel and metalness in the blue channel.
+    pub metallic_roughness: image::Data,
+}
+
+/// Converts linear specular-glossiness parameters to metallic-roughness.
+///
+/// The metalness is solved so that the diffuse and specular reflectance of
+/// the result match the input as closely as possible, as in the reference
+/// conversion of the `KHR_materials_pbrSpecularGlossiness` extension.
+pub fn convert(diffuse: [f32; 4], specular: [f32; 3], glossiness: f32) -> MetallicRoughness {
+    let max_specular = specular[0].max(specular[1]).max(specular[2]);
+    let one_minus_specular_strength = 1.0 - max_specular;
+    let metallic = solve_metallic(
+        perceived_brightness([diffuse[0], diffuse[1], diffuse[2]]),
+        perceived_brightness(specular),
+        one_minus_specular_strength,
+    );
+
+    let mut base_color = [0.0, 0.0, 0.0, diffuse[3]];
+    for i in 0..3 {
+        let from_diffuse = diffuse[i] * one_minus_specular_strength
+            / (1.0 - DIELECTRIC_SPECULAR)
+            / (1.0 - metallic).max(EPSILON);
+        let from_specular =
+            (specular[i] - DIELECTRIC_SPECULAR * (1.0 - metallic)) / metallic.max(EPSILON);
+        let t = metallic * metallic;
+        base_color[i] = (from_diffuse + (from_specular - from_diffuse) * t).clamp(0.0, 1.0);
+    }
+
+    MetallicRoughness {
+        base_color,
+        metallic,
+        roughness: 1.0 - glossiness,
+    }
+}
+
+fn perceived_brightness(color: [f32; 3]) -> f32 {
+    (0.299 * color[0] * color[0] + 0.587 * color[1] * color[1] + 0.114 * color[2] * color[2]).sqrt()
+}
+
+fn solve_metallic(diffuse: f32, specular: f32, one_minus_specular_strength: f32) -> f32 {
+    if specular < DIELECTRIC_SPECULAR {
+        return 0.0;
+    }
+    let a = DIELECTRIC_SPECULAR;
+    let b = diffuse * one_minus_specular_strength / (1.0 - DIELECTRIC_SPECULAR) + specular
+        - 2.0 * DIELECTRIC_SPECULAR;
+    let c = DIELECTRIC_SPECULAR - specular;
+    let discriminant = (b * b - 4.0 * a * c).max(0.0);
+    ((-b + discriminant.sqrt()) / (2.0 * a)).clamp(0.0, 1.0)
+}
+
+impl<'a> PbrSpecularGlossiness<'a> {
+    /// Converts the factors of the material to the metallic-roughness model.
+    pub fn to_metallic_roughness(&self) -> MetallicRoughness {
+        convert(
+            self.diffuse_factor(),
+            self.specular_factor(),
+            self.glossiness_factor(),
+        )
+    }
+
+    /// Bakes metallic-roughness textures from the decoded diffuse and
+    /// specular-glossiness images of the material, combined with its factors.
+    ///
+    /// Both images are assumed to share the same texture co-ordinates. The
+    /// output has the size of the diffuse image if provided, and the other
+    /// image is resampled to match.
+    ///
+    /// Returns `None` if neither image is provided or an image does not have
+    /// 8 bits per channel.
+    #[cfg(feature = "import")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+    pub fn bake_textures(
+        &self,
+        diffuse: Option<&image::Data>,
+        specular_glossiness: Option<&image::Data>,
+    ) -> Option<BakedTextures> {
+        let (width, height) = diffuse
+            .or(specular_glossiness)
+            .map(|data| (data.width, data.height))?;
+        let diffuse_factor = self.diffuse_factor();
+        let specular_factor = self.specular_factor();
+        let glossiness_factor = self.glossiness_factor();
+
+        let texel_count = width as usize * height as usize;
+        let mut base_color = Vec::with_capacity(texel_count * 4);
+        let mut metallic_roughness = Vec::with_capacity(texel_count * 4);
+        for y in 0..height {
+            for x in 0..width {
+                let mut diffuse_color = diffuse_factor;
+                if let Some(data) = diffuse {
+                    let texel = sample(data, x, y, width, height)?;
+                    for i in 0..3 {
+                        diffuse_color[i] *= srgb_to_linear(texel[i]);
+                    }
+                    diffuse_color[3] *= texel[3];
+                }
+                let mut specular = specular_factor;
+                let mut glossiness = glossiness_factor;
+                if let Some(data) = specular_glossiness {
+                    let texel = sample(data, x, y, width, height)?;
+                    for i in 0..3 {
+                        specular[i] *= srgb_to_linear(texel[i]);
+                    }
+                    glossiness *= texel[3];
+                }
+
+                let converted = convert(diffuse_color, specular, glossiness);
+                base_color.extend_from_slice(&[
+                    to_u8(linear_to_srgb(converted.base_color[0])),
+                    to_u8(linear_to_srgb(converted.base_color[1])),
+                    to_u8(linear_to_srgb(converted.base_color[2])),
+                    to_u8(converted.base_color[3]),
+                ]);
+                metallic_roughness.extend_from_slice(&[
+                    0,
+                    to_u8(converted.roughness),
+                    to_u8(converted.metallic),
+                    255,
+                ]);
+            }
+        }
+
+        let data = |pixels| image::Data {
+            pixels,
+            format: image::Format::R8G8B8A8,
+            width,
+            height,
+        };
+        Some(BakedTextures {
+            base_color: data(base_color),
+            metallic_roughness: data(metallic_roughness),
+        })
+    }
+}
+
+impl<'a> Material<'a> {
+    /// Rewrites a specular-glossiness material as a metallic-roughness one,
+    /// removing the `KHR_materials_pbrSpecularGlossiness` extension.
+    ///
+    /// Without a baked base color texture the diffuse texture is reused as the
+    /// base color texture. Without a baked metallic-roughness texture only the
+    /// converted factors are kept. The caller is responsible for removing the
+    /// extension from `extensionsUsed` and `extensionsRequired` once no
+    /// material uses it.
+    ///
+    /// Returns `None` if the material does not use the specular-glossiness
+    /// model.
+    pub fn to_metallic_roughness_json(
+        &self,
+        textures: &Textures,
+    ) -> Option<json::material::Material> {
+        let spec_gloss = self.pbr_specular_glossiness()?;
+        let converted = spec_gloss.to_metallic_roughness();
+
+        let mut json = self.json.clone();
+        let pbr = &mut json.pbr_metallic_roughness;
+        match textures.base_color.clone() {
+            Some(info) => {
+                pbr.base_color_factor = json::material::PbrBaseColorFactor([1.0; 4]);
+                pbr.base_color_texture = Some(info);
+            }
+            None => {
+                pbr.base_color_factor = json::material::PbrBaseColorFactor(converted.base_color);
+                pbr.base_color_texture = spec_gloss.json.diffuse_texture.clone();
+            }
+        }
+        match textures.metallic_roughness.clone() {
+            Some(info) => {
+                pbr.metallic_factor = json::material::StrengthFactor(1.0);
+                pbr.roughness_factor = json::material::StrengthFactor(1.0);
+                pbr.metallic_roughness_texture = Some(info);
+            }
+            None => {
+                pbr.metallic_factor = json::material::StrengthFactor(converted.metallic);
+                pbr.roughness_factor = json::material::StrengthFactor(converted.roughness);
+                pbr.metallic_roughness_texture = None;
+            }
+        }
+
+        if let Some(extensions) = json.extensions.as_mut() {
+            extensions.pbr_specular_glossiness = None;
+        }
+        let is_empty = match json.extensions.as_ref().map(json::serialize::to_value) {
+            Some(Ok(json::Value::Object(map))) => map.is_empty(),
+            _ => false,
+        };
+        if is_empty {
+            json.extensions = None;
+        }
+        Some(json)
+    }
+}
+
+/// Samples the texel of `data` corresponding to texel `(x, y)` of an image
+/// with the given size, as normalized RGBA.
+#[cfg(feature = "import")]
+fn sample(data: &image::Data, x: u32, y: u32, width: u32, height: u32) -> Option<[f32; 4]> {
+    let channels = match data.format {
+        image::Format::R8 => 1,
+        image::Format::R8G8 => 2,
+        image::Format::R8G8B8 => 3,
+        image::Format::R8G8B8A8 => 4,
+        _ => return None,
+    };
+    let x = (x as u64 * data.width as u64 / width as u64) as usize;
+    let y = (y as u64 * data.height as u64 / height as u64) as usize;
+    let offset = (y * data.width as usize + x) * channels;
+    let texel = data.pixels.get(offset..offset + channels)?;
+    let value = |i: usize| texel[i] as f32 / 255.0;
+    Some(match channels {
+        1 => [value(0), value(0), value(0), 1.0],
+        2 => [value(0), value(0), value(0), value(1)],
+        3 => [value(0), value(1), value(2), 1.0],
+        _ => [value(0), value(1), value(2), value(3)],
+    })
+}
+
+#[cfg(feature = "import")]
+fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+#[cfg(feature = "import")]
+fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+#[cfg(feature = "import")]
+fn to_u8(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
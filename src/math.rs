@@ -163,7 +163,6 @@ impl Vector4 {
         [self.x, self.y, self.z, self.w]
     }
 
-    #[cfg(test)]
     pub fn from_array([x, y, z, w]: [f32; 4]) -> Self {
         Self { x, y, z, w }
     }
@@ -250,7 +249,6 @@ impl Matrix4 {
         }
     }
 
-    #[cfg(test)]
     pub fn from_array([x, y, z, w]: [[f32; 4]; 4]) -> Matrix4 {
         Matrix4 {
             x: Vector4::from_array(x),
@@ -260,6 +258,11 @@ impl Matrix4 {
         }
     }
 
+    /// Create the identity matrix.
+    pub fn identity() -> Matrix4 {
+        Matrix4::from_nonuniform_scale(1.0, 1.0, 1.0)
+    }
+
     /// Create a homogeneous transformation matrix from a translation vector.
     #[rustfmt::skip]
     pub fn from_translation(v: Vector3) -> Matrix4 {
@@ -308,6 +311,36 @@ impl Matrix4 {
         }
     }
 
+    /// Invert the matrix, returning `None` if it is singular.
+    pub fn inverse(&self) -> Option<Matrix4> {
+        let m = self.as_array();
+        // Cofactors of the first column, reused for the determinant.
+        let c00 = minor(&m, 0, 0);
+        let c01 = -minor(&m, 0, 1);
+        let c02 = minor(&m, 0, 2);
+        let c03 = -minor(&m, 0, 3);
+        let determinant = m[0][0] * c00 + m[0][1] * c01 + m[0][2] * c02 + m[0][3] * c03;
+        if determinant == 0.0 || !determinant.is_finite() {
+            return None;
+        }
+        let mut inverse = [[0.0; 4]; 4];
+        for (col, column) in inverse.iter_mut().enumerate() {
+            for (row, value) in column.iter_mut().enumerate() {
+                // The inverse is the transposed cofactor matrix over the
+                // determinant, so element (col, row) is cofactor (row, col).
+                let sign = if (row + col) % 2 == 0 { 1.0 } else { -1.0 };
+                *value = sign * minor(&m, row, col) / determinant;
+            }
+        }
+        Some(Matrix4::from_array(inverse))
+    }
+
+    /// Transform a point, treating the matrix as an affine transformation.
+    pub fn transform_point(&self, [x, y, z]: [f32; 3]) -> [f32; 3] {
+        let p = self.x * x + self.y * y + self.z * z + self.w;
+        [p.x, p.y, p.z]
+    }
+
     pub fn as_array(&self) -> [[f32; 4]; 4] {
         [
             self.x.as_array(),
@@ -318,6 +351,22 @@ impl Matrix4 {
     }
 }
 
+/// Returns the determinant of the 3x3 matrix left after removing the given
+/// column and row of a column-major 4x4 matrix.
+fn minor(m: &[[f32; 4]; 4], col: usize, row: usize) -> f32 {
+    let mut sub = [[0.0; 3]; 3];
+    let cols = (0..4).filter(|&c| c != col);
+    for (sub_col, c) in sub.iter_mut().zip(cols) {
+        let rows = (0..4).filter(|&r| r != row);
+        for (value, r) in sub_col.iter_mut().zip(rows) {
+            *value = m[c][r];
+        }
+    }
+    sub[0][0] * (sub[1][1] * sub[2][2] - sub[2][1] * sub[1][2])
+        - sub[1][0] * (sub[0][1] * sub[2][2] - sub[2][1] * sub[0][2])
+        + sub[2][0] * (sub[0][1] * sub[1][2] - sub[1][1] * sub[0][2])
+}
+
 impl ops::Mul<Matrix4> for Matrix4 {
     type Output = Matrix4;
     fn mul(self, rhs: Matrix4) -> Self::Output {
@@ -56,6 +56,7 @@ pub mod iter;
 #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
 pub mod util;
 
+use crate::math::Matrix4;
 use crate::{Accessor, Buffer, Document, Material};
 
 #[cfg(feature = "utils")]
@@ -80,6 +81,16 @@ pub struct Bounds<T> {
     pub max: T,
 }
 
+/// A sphere enclosing a set of vertex positions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoundingSphere {
+    /// The center of the sphere.
+    pub center: [f32; 3],
+
+    /// The radius of the sphere.
+    pub radius: f32,
+}
+
 /// A set of primitives to be rendered.
 #[derive(Clone, Debug)]
 pub struct Mesh<'a> {
@@ -119,13 +130,78 @@ pub struct Primitive<'a> {
     json: &'a json::mesh::Primitive,
 }
 
+impl Bounds<[f32; 3]> {
+    /// Returns the smallest box containing both boxes.
+    pub fn union(&self, other: &BoundingBox) -> BoundingBox {
+        let mut bounds = self.clone();
+        bounds.extend(other.min);
+        bounds.extend(other.max);
+        bounds
+    }
+
+    /// Grows the box to contain the given point.
+    pub fn extend(&mut self, point: [f32; 3]) {
+        for (i, x) in point.iter().enumerate() {
+            self.min[i] = self.min[i].min(*x);
+            self.max[i] = self.max[i].max(*x);
+        }
+    }
+
+    /// Returns the center of the box.
+    pub fn center(&self) -> [f32; 3] {
+        [
+            (self.min[0] + self.max[0]) * 0.5,
+            (self.min[1] + self.max[1]) * 0.5,
+            (self.min[2] + self.max[2]) * 0.5,
+        ]
+    }
+
+    /// Returns the sphere centered on the box that passes through its corners.
+    pub fn bounding_sphere(&self) -> BoundingSphere {
+        let center = self.center();
+        let half_extents = [
+            self.max[0] - center[0],
+            self.max[1] - center[1],
+            self.max[2] - center[2],
+        ];
+        BoundingSphere {
+            center,
+            radius: half_extents.iter().map(|x| x * x).sum::<f32>().sqrt(),
+        }
+    }
+
+    /// Returns the axis-aligned box containing this box transformed by a
+    /// column-major affine matrix.
+    pub fn transform(&self, matrix: &[[f32; 4]; 4]) -> BoundingBox {
+        let matrix = Matrix4::from_array(*matrix);
+        let corner = |i: usize| {
+            let pick = |axis: usize| {
+                if i & (1 << axis) == 0 {
+                    self.min[axis]
+                } else {
+                    self.max[axis]
+                }
+            };
+            matrix.transform_point([pick(0), pick(1), pick(2)])
+        };
+        let mut bounds = Bounds {
+            min: corner(0),
+            max: corner(0),
+        };
+        for i in 1..8 {
+            bounds.extend(corner(i));
+        }
+        bounds
+    }
+}
+
 /// Mesh primitive reader.
 #[derive(Clone, Debug)]
 pub struct Reader<'a, 's, F>
 where
     F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
 {
-    pub(crate) primitive: &'a Primitive<'a>,
+    pub(crate) primitive: Primitive<'a>,
     pub(crate) get_buffer_data: F,
 }
 
@@ -144,11 +220,32 @@ impl<'a> Mesh<'a> {
         self.index
     }
 
+    /// Returns the bounds of the primitives of the mesh, including the
+    /// extents of their morph targets.
+    ///
+    /// Returns `None` if the mesh has no primitives.
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        let mut primitives = self.primitives();
+        let first = primitives.next()?.morphed_bounding_box();
+        Some(primitives.fold(first, |bounds, primitive| {
+            bounds.union(&primitive.morphed_bounding_box())
+        }))
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
     }
 
+    /// Returns the XMP metadata packet of this mesh as defined by the
+    /// `KHR_xmp_json_ld` extension.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_xmp_json_ld")))]
+    pub fn xmp_packet(&self) -> Option<crate::khr_xmp_json_ld::Packet<'a>> {
+        self.document
+            .xmp_packet(self.json.extensions.as_ref()?.khr_xmp_json_ld.as_ref())
+    }
+
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
@@ -195,11 +292,55 @@ impl<'a> Primitive<'a> {
         Bounds { min, max }
     }
 
+    /// Returns the bounds of the `POSITION` vertex attribute, grown to contain
+    /// the displaced positions of its morph targets for any weights between
+    /// `0` and `1`.
+    ///
+    /// Morph targets without position bounds are ignored.
+    pub fn morphed_bounding_box(&self) -> BoundingBox {
+        let mut bounds = self.bounding_box();
+        for target in self.morph_targets() {
+            let accessor = match target.positions() {
+                Some(accessor) => accessor,
+                None => continue,
+            };
+            let min = accessor
+                .min()
+                .and_then(|min| json::deserialize::from_value::<[f32; 3]>(min).ok());
+            let max = accessor
+                .max()
+                .and_then(|max| json::deserialize::from_value::<[f32; 3]>(max).ok());
+            if let (Some(min), Some(max)) = (min, max) {
+                for (bound, displacement) in bounds.min.iter_mut().zip(min) {
+                    *bound += displacement.min(0.0);
+                }
+                for (bound, displacement) in bounds.max.iter_mut().zip(max) {
+                    *bound += displacement.max(0.0);
+                }
+            }
+        }
+        bounds
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
     }
 
+    /// Returns an `Iterator` that visits the feature ID sets of this primitive
+    /// as defined by the `EXT_mesh_features` extension.
+    #[cfg(feature = "EXT_mesh_features")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_mesh_features")))]
+    pub fn feature_ids(&self) -> crate::ext_mesh_features::FeatureIds<'a> {
+        let json = self
+            .json
+            .extensions
+            .as_ref()
+            .and_then(|extensions| extensions.ext_mesh_features.as_ref())
+            .map_or(&[][..], |features| &features.feature_ids[..]);
+        crate::ext_mesh_features::FeatureIds::new(self.mesh.document, self.clone(), json)
+    }
+
     /// Return the accessor with the given semantic.
     pub fn get(&self, semantic: &Semantic) -> Option<Accessor<'a>> {
         self.json
@@ -208,6 +349,11 @@ impl<'a> Primitive<'a> {
             .map(|index| self.mesh.document.accessors().nth(index.value()).unwrap())
     }
 
+    /// Returns the mesh this primitive belongs to.
+    pub fn mesh(&self) -> Mesh<'a> {
+        self.mesh.clone()
+    }
+
     /// Returns the internal JSON index.
     pub fn index(&self) -> usize {
         self.index
@@ -277,18 +423,101 @@ impl<'a> Primitive<'a> {
         }
     }
 
+    /// Returns the material used when the given variant is active, falling
+    /// back to the default material of the primitive.
+    #[cfg(feature = "KHR_materials_variants")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_variants")))]
+    pub fn material_for_variant(
+        &self,
+        variant: &crate::khr_materials_variants::Variant,
+    ) -> Material<'a> {
+        self.mapping_for_variant(variant.index())
+            .map(|mapping| mapping.material())
+            .unwrap_or_else(|| self.material())
+    }
+
+    /// Returns the first mapping that applies to the variant with the given
+    /// index.
+    #[cfg(feature = "KHR_materials_variants")]
+    pub(crate) fn mapping_for_variant(
+        &self,
+        variant: usize,
+    ) -> Option<crate::khr_materials_variants::Mapping<'a>> {
+        self.mappings()
+            .find(|mapping| mapping.variants().contains(&(variant as u32)))
+    }
+
     /// Constructs the primitive reader.
     #[cfg(feature = "utils")]
     #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
-    pub fn reader<'s, F>(&'a self, get_buffer_data: F) -> Reader<'a, 's, F>
+    pub fn reader<'s, F>(&self, get_buffer_data: F) -> Reader<'a, 's, F>
     where
         F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
     {
         Reader {
-            primitive: self,
+            primitive: self.clone(),
             get_buffer_data,
         }
     }
+
+    /// Reads every vertex stream of the primitive into owned buffers.
+    ///
+    /// Positions, normals, tangents, texture co-ordinates and weights are
+    /// converted to `f32`, colors to RGBA `f32`, indices to `u32` and joints
+    /// to `u16`. Attribute sets are read from set `0` until the first
+    /// missing set. Returns `None` if the positions cannot be read.
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn extract<'s, F>(&self, get_buffer_data: F) -> Option<util::extract::PrimitiveData>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let reader = self.reader(get_buffer_data.clone());
+        Some(util::extract::PrimitiveData {
+            mode: self.mode(),
+            material: self.material().index(),
+            indices: reader
+                .read_indices()
+                .map(|indices| indices.into_u32().collect()),
+            positions: reader.read_positions()?.collect(),
+            normals: reader.read_normals().map(Iterator::collect),
+            tangents: reader.read_tangents().map(Iterator::collect),
+            tex_coords: util::extract::read_sets(|set| {
+                Some(reader.read_tex_coords(set)?.into_f32().collect())
+            }),
+            colors: util::extract::read_sets(|set| {
+                Some(reader.read_colors(set)?.into_rgba_f32().collect())
+            }),
+            joints: util::extract::read_sets(|set| {
+                Some(reader.read_joints(set)?.into_u16().collect())
+            }),
+            weights: util::extract::read_sets(|set| {
+                Some(reader.read_weights(set)?.into_f32().collect())
+            }),
+            morph_targets: reader
+                .read_morph_targets()
+                .map(
+                    |(positions, normals, tangents)| util::extract::MorphTargetData {
+                        positions: positions.map(Iterator::collect),
+                        normals: normals.map(Iterator::collect),
+                        tangents: tangents.map(Iterator::collect),
+                    },
+                )
+                .collect(),
+            #[cfg(feature = "extras")]
+            custom: self
+                .attributes()
+                .filter_map(|(semantic, accessor)| match semantic {
+                    Semantic::Extras(name) => {
+                        let attribute =
+                            util::extract::read_custom(accessor, get_buffer_data.clone())?;
+                        Some((name, attribute))
+                    }
+                    _ => None,
+                })
+                .collect(),
+        })
+    }
 }
 
 #[cfg(feature = "utils")]
@@ -361,6 +590,78 @@ where
             })
     }
 
+    /// Reads the vertex draw sequence of the primitive as `u32`, generating
+    /// sequential indices when the primitive is not indexed.
+    fn read_draw_sequence(&self) -> Option<Vec<u32>> {
+        match self.primitive.indices() {
+            Some(_) => Some(self.read_indices()?.into_u32().collect()),
+            None => {
+                let count = self.primitive.get(&Semantic::Positions)?.count();
+                Some((0..count as u32).collect())
+            }
+        }
+    }
+
+    /// Reads the triangles of a `Triangles`, `TriangleStrip` or
+    /// `TriangleFan` primitive as a triangle list.
+    ///
+    /// Strips and fans are unrolled with the winding order of their first
+    /// triangle, and degenerate triangles are skipped. Non-indexed
+    /// primitives are assigned sequential indices. Returns `None` for point
+    /// and line primitives, or if the indices cannot be read.
+    pub fn read_triangles(&self) -> Option<Vec<[u32; 3]>> {
+        util::topology::triangles(self.primitive.mode(), &self.read_draw_sequence()?)
+    }
+
+    /// Reads the lines of a `Lines`, `LineStrip` or `LineLoop` primitive as a
+    /// line list.
+    ///
+    /// Non-indexed primitives are assigned sequential indices. Returns `None`
+    /// for point and triangle primitives, or if the indices cannot be read.
+    pub fn read_lines(&self) -> Option<Vec<[u32; 2]>> {
+        util::topology::lines(self.primitive.mode(), &self.read_draw_sequence()?)
+    }
+
+    /// Reads the unique edges of the triangles of a primitive, for drawing it
+    /// as a wireframe.
+    ///
+    /// Each edge shared by several triangles is listed once. Returns `None`
+    /// under the same conditions as `read_triangles`.
+    pub fn read_wireframe(&self) -> Option<Vec<[u32; 2]>> {
+        Some(util::topology::edges(&self.read_triangles()?))
+    }
+
+    /// Generates flat normals for the triangles of the primitive, as
+    /// required by the specification when normals are not provided.
+    ///
+    /// Flat shading cannot share vertices between triangles, so one normal
+    /// is yielded per triangle corner in the order of `read_triangles`, and
+    /// the other vertex attributes must be unwelded to match. Returns `None`
+    /// if the positions or triangles cannot be read.
+    pub fn generate_flat_normals(&self) -> Option<util::generate::GeneratedNormals> {
+        let positions = self.read_positions()?.collect::<Vec<_>>();
+        let triangles = self.read_triangles()?;
+        let normals = util::generate::flat_normals(&positions, &triangles);
+        Some(
+            normals
+                .into_iter()
+                .flat_map(|normal| vec![normal; 3])
+                .collect::<Vec<_>>()
+                .into_iter(),
+        )
+    }
+
+    /// Generates smooth normals for the vertices of the primitive, averaging
+    /// the normals of the triangles using each vertex, weighted by area.
+    ///
+    /// Vertices not used by any triangle have a zero normal. Returns `None`
+    /// if the positions or triangles cannot be read.
+    pub fn generate_smooth_normals(&self) -> Option<util::generate::GeneratedNormals> {
+        let positions = self.read_positions()?.collect::<Vec<_>>();
+        let triangles = self.read_triangles()?;
+        Some(util::generate::smooth_normals(&positions, &triangles).into_iter())
+    }
+
     /// Visits the joint indices of the primitive.
     pub fn read_joints(&self, set: u32) -> Option<util::ReadJoints<'s>> {
         use self::util::ReadJoints;
@@ -395,6 +696,33 @@ where
             })
     }
 
+    /// Visits the texture co-ordinates used to sample a material texture,
+    /// with its `KHR_texture_transform` baked in.
+    ///
+    /// `tex_coord` and `transform` are the `tex_coord()` and
+    /// `texture_transform()` of any texture reference, such as a
+    /// `texture::Info`, `NormalTexture` or `OcclusionTexture`. The co-ordinate
+    /// set is chosen by the transform's `texCoord` override if present.
+    /// Without a transform the co-ordinates are returned as stored, converted
+    /// to `f32`.
+    #[cfg(feature = "KHR_texture_transform")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_texture_transform")))]
+    pub fn read_transformed_tex_coords(
+        &self,
+        tex_coord: u32,
+        transform: Option<crate::texture::TextureTransform>,
+    ) -> Option<util::tex_coords::Transformed<'s>> {
+        let (set, matrix) = match transform {
+            Some(transform) => (
+                transform.tex_coord().unwrap_or(tex_coord),
+                transform.matrix(),
+            ),
+            None => (tex_coord, util::tex_coords::IDENTITY),
+        };
+        self.read_tex_coords(set)
+            .map(|tex_coords| util::tex_coords::Transformed::new(tex_coords.into_f32(), matrix))
+    }
+
     /// Visits the joint weights of the primitive.
     pub fn read_weights(&self, set: u32) -> Option<util::ReadWeights<'s>> {
         use self::accessor::DataType;
@@ -420,6 +748,64 @@ where
             reader: self.clone(),
         }
     }
+
+    /// Reads the positions, normals and tangents of the primitive deformed on
+    /// the CPU.
+    ///
+    /// The morph targets are applied first with the given `weights`, then
+    /// linear blend skinning with `joint_matrices` over every
+    /// `JOINTS_n`/`WEIGHTS_n` set. Normals and tangents are renormalized.
+    /// Skinning is skipped when no joint matrices are provided or the
+    /// primitive has no joints.
+    ///
+    /// Returns `None` if the positions cannot be read.
+    pub fn read_deformed(
+        &self,
+        weights: &[f32],
+        joint_matrices: Option<&[[[f32; 4]; 4]]>,
+    ) -> Option<util::deform::Deformed> {
+        let deformed = util::deform::Deformed {
+            positions: self.read_positions()?.collect(),
+            normals: self.read_normals().map(Iterator::collect),
+            tangents: self.read_tangents().map(Iterator::collect),
+        };
+        Some(self.deform(deformed, weights, joint_matrices, None))
+    }
+
+    /// Applies the morph targets and skinning of the primitive to vertex
+    /// streams read from it.
+    ///
+    /// When skinning, vertices without any influence are transformed by
+    /// `unskinned` if given.
+    pub(crate) fn deform(
+        &self,
+        mut deformed: util::deform::Deformed,
+        weights: &[f32],
+        joint_matrices: Option<&[[[f32; 4]; 4]]>,
+        unskinned: Option<&[[f32; 4]; 4]>,
+    ) -> util::deform::Deformed {
+        for ((positions, normals, tangents), weight) in self.read_morph_targets().zip(weights) {
+            deformed.morph(*weight, positions, normals, tangents);
+        }
+
+        if let Some(joint_matrices) = joint_matrices {
+            let mut influences = Vec::new();
+            for set in 0.. {
+                match (self.read_joints(set), self.read_weights(set)) {
+                    (Some(joints), Some(weights)) => influences.push(
+                        joints
+                            .into_u16()
+                            .zip(weights.into_f32())
+                            .collect::<Vec<_>>(),
+                    ),
+                    _ => break,
+                }
+            }
+            deformed.skin(joint_matrices, &influences, unskinned);
+        }
+        deformed.normalize();
+        deformed
+    }
 }
 
 impl<'a> MorphTarget<'a> {
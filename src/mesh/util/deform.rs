@@ -0,0 +1,172 @@
+/// Vertex streams of a primitive deformed by morph targets and skinning.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Deformed {
+    /// XYZ vertex positions.
+    pub positions: Vec<[f32; 3]>,
+
+    /// XYZ vertex normals of unit length, if the primitive has normals.
+    pub normals: Option<Vec<[f32; 3]>>,
+
+    /// XYZW vertex tangents, if the primitive has tangents. The XYZ
+    /// components have unit length and W is the unchanged handedness.
+    pub tangents: Option<Vec<[f32; 4]>>,
+}
+
+/// The joints and weights of a vertex in one `JOINTS_n`/`WEIGHTS_n` set.
+pub(crate) type Influences = ([u16; 4], [f32; 4]);
+
+impl Deformed {
+    /// Adds the weighted displacements of a morph target.
+    pub(crate) fn morph(
+        &mut self,
+        weight: f32,
+        positions: Option<impl Iterator<Item = [f32; 3]>>,
+        normals: Option<impl Iterator<Item = [f32; 3]>>,
+        tangents: Option<impl Iterator<Item = [f32; 3]>>,
+    ) {
+        if weight == 0.0 {
+            return;
+        }
+        if let Some(displacements) = positions {
+            for (position, displacement) in self.positions.iter_mut().zip(displacements) {
+                add_scaled(position, &displacement, weight);
+            }
+        }
+        if let (Some(normals), Some(displacements)) = (self.normals.as_mut(), normals) {
+            for (normal, displacement) in normals.iter_mut().zip(displacements) {
+                add_scaled(normal, &displacement, weight);
+            }
+        }
+        if let (Some(tangents), Some(displacements)) = (self.tangents.as_mut(), tangents) {
+            for (tangent, displacement) in tangents.iter_mut().zip(displacements) {
+                add_scaled(tangent, &displacement, weight);
+            }
+        }
+    }
+
+    /// Applies linear blend skinning, where `influences` holds the joints and
+    /// weights of every vertex for each set.
+    ///
+    /// Influences with a weight of zero or an out-of-range joint are skipped.
+    /// Vertices without any influence are transformed by `unskinned` if
+    /// given, and are otherwise left in place.
+    pub(crate) fn skin(
+        &mut self,
+        joint_matrices: &[[[f32; 4]; 4]],
+        influences: &[Vec<Influences>],
+        unskinned: Option<&[[f32; 4]; 4]>,
+    ) {
+        for (vertex, position) in self.positions.iter_mut().enumerate() {
+            let mut matrix = [[0.0; 4]; 4];
+            let mut total_weight = 0.0;
+            let vertex_influences = influences.iter().filter_map(|set| set.get(vertex));
+            for (joints, weights) in vertex_influences {
+                for (joint, weight) in joints.iter().zip(weights) {
+                    if *weight == 0.0 {
+                        continue;
+                    }
+                    let joint_matrix = match joint_matrices.get(*joint as usize) {
+                        Some(joint_matrix) => joint_matrix,
+                        None => continue,
+                    };
+                    total_weight += weight;
+                    for (column, joint_column) in matrix.iter_mut().zip(joint_matrix) {
+                        for (x, joint_x) in column.iter_mut().zip(joint_column) {
+                            *x += weight * joint_x;
+                        }
+                    }
+                }
+            }
+            if total_weight == 0.0 {
+                match unskinned {
+                    Some(unskinned) => matrix = *unskinned,
+                    None => continue,
+                }
+            }
+
+            let [x, y, z] = *position;
+            let mut skinned = matrix[3];
+            for (axis, column) in [x, y, z].iter().zip(&matrix) {
+                for (out, value) in skinned.iter_mut().zip(column) {
+                    *out += axis * value;
+                }
+            }
+            *position = [skinned[0], skinned[1], skinned[2]];
+
+            // Normals are transformed by the inverse transpose of the blended
+            // matrix, which is proportional to its cofactor matrix.
+            let columns = [
+                [matrix[0][0], matrix[0][1], matrix[0][2]],
+                [matrix[1][0], matrix[1][1], matrix[1][2]],
+                [matrix[2][0], matrix[2][1], matrix[2][2]],
+            ];
+            if let Some(normal) = self.normals.as_mut().and_then(|n| n.get_mut(vertex)) {
+                let cofactors = [
+                    cross(&columns[1], &columns[2]),
+                    cross(&columns[2], &columns[0]),
+                    cross(&columns[0], &columns[1]),
+                ];
+                let sign = dot(&columns[0], &cofactors[0]).signum();
+                let transformed = transform_vector(&cofactors, normal);
+                *normal = [
+                    sign * transformed[0],
+                    sign * transformed[1],
+                    sign * transformed[2],
+                ];
+            }
+            if let Some(tangent) = self.tangents.as_mut().and_then(|t| t.get_mut(vertex)) {
+                let transformed = transform_vector(&columns, &[tangent[0], tangent[1], tangent[2]]);
+                *tangent = [transformed[0], transformed[1], transformed[2], tangent[3]];
+            }
+        }
+    }
+
+    /// Renormalizes the normals and the XYZ components of the tangents.
+    pub(crate) fn normalize(&mut self) {
+        for normal in self.normals.iter_mut().flatten() {
+            normalize(normal);
+        }
+        for tangent in self.tangents.iter_mut().flatten() {
+            let mut xyz = [tangent[0], tangent[1], tangent[2]];
+            normalize(&mut xyz);
+            tangent[..3].copy_from_slice(&xyz);
+        }
+    }
+}
+
+fn add_scaled(vector: &mut [f32], displacement: &[f32; 3], weight: f32) {
+    for (x, d) in vector.iter_mut().zip(displacement) {
+        *x += weight * d;
+    }
+}
+
+fn transform_vector(columns: &[[f32; 3]; 3], vector: &[f32; 3]) -> [f32; 3] {
+    let mut out = [0.0; 3];
+    for (column, x) in columns.iter().zip(vector) {
+        for (out, value) in out.iter_mut().zip(column) {
+            *out += x * value;
+        }
+    }
+    out
+}
+
+fn cross(a: &[f32; 3], b: &[f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn dot(a: &[f32; 3], b: &[f32; 3]) -> f32 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+fn normalize(vector: &mut [f32; 3]) {
+    let length = dot(vector, vector).sqrt();
+    if length > 0.0 {
+        for x in vector {
+            *x /= length;
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use crate::accessor::{DataType, Dimensions};
+use crate::mesh::Mode;
+use crate::Semantic;
+
+#[cfg(feature = "extras")]
+use crate::accessor::{util::components, Accessor};
+#[cfg(feature = "extras")]
+use crate::Buffer;
+
+/// The vertex streams of a primitive, read into owned buffers of canonical
+/// types.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrimitiveData {
+    /// The topology type of the primitive.
+    pub mode: Mode,
+
+    /// The index of the material of the primitive, or `None` for the
+    /// default material.
+    pub material: Option<usize>,
+
+    /// The vertex draw sequence, or `None` for non-indexed primitives.
+    pub indices: Option<Vec<u32>>,
+
+    /// XYZ vertex positions.
+    pub positions: Vec<[f32; 3]>,
+
+    /// XYZ vertex normals.
+    pub normals: Option<Vec<[f32; 3]>>,
+
+    /// XYZW vertex tangents.
+    pub tangents: Option<Vec<[f32; 4]>>,
+
+    /// UV texture co-ordinates, indexed by set.
+    pub tex_coords: Vec<Vec<[f32; 2]>>,
+
+    /// Linear RGBA vertex colors, indexed by set.
+    pub colors: Vec<Vec<[f32; 4]>>,
+
+    /// Joint indices, indexed by set.
+    pub joints: Vec<Vec<[u16; 4]>>,
+
+    /// Joint weights, indexed by set.
+    pub weights: Vec<Vec<[f32; 4]>>,
+
+    /// Morph target displacements.
+    pub morph_targets: Vec<MorphTargetData>,
+
+    /// Application-specific attributes whose names start with an
+    /// underscore, paired with their name without the underscore.
+    #[cfg(feature = "extras")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extras")))]
+    pub custom: Vec<(String, CustomAttribute)>,
+}
+
+/// The displacements of a morph target.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MorphTargetData {
+    /// XYZ vertex position displacements.
+    pub positions: Option<Vec<[f32; 3]>>,
+
+    /// XYZ vertex normal displacements.
+    pub normals: Option<Vec<[f32; 3]>>,
+
+    /// XYZ vertex tangent displacements.
+    pub tangents: Option<Vec<[f32; 3]>>,
+}
+
+/// An application-specific vertex attribute, kept in its stored type.
+#[cfg(feature = "extras")]
+#[cfg_attr(docsrs, doc(cfg(feature = "extras")))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CustomAttribute {
+    /// The dimensions of each element.
+    pub dimensions: Dimensions,
+
+    /// Whether integer components are normalized.
+    pub normalized: bool,
+
+    /// The components of every element, in order.
+    pub data: CustomData,
+}
+
+/// The flattened components of an application-specific vertex attribute.
+#[cfg(feature = "extras")]
+#[cfg_attr(docsrs, doc(cfg(feature = "extras")))]
+#[derive(Clone, Debug, PartialEq)]
+pub enum CustomData {
+    /// Components of type `i8`.
+    I8(Vec<i8>),
+    /// Components of type `u8`.
+    U8(Vec<u8>),
+    /// Components of type `i16`.
+    I16(Vec<i16>),
+    /// Components of type `u16`.
+    U16(Vec<u16>),
+    /// Components of type `u32`.
+    U32(Vec<u32>),
+    /// Components of type `f32`.
+    F32(Vec<f32>),
+}
+
+/// Vertex attributes interleaved into a single little-endian buffer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Interleaved {
+    /// The size in bytes of each vertex.
+    pub stride: usize,
+
+    /// The layout of the attributes within a vertex.
+    pub attributes: Vec<InterleavedAttribute>,
+
+    /// The interleaved vertex data.
+    pub data: Vec<u8>,
+}
+
+/// The layout of an attribute within an interleaved vertex.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InterleavedAttribute {
+    /// The attribute semantic.
+    pub semantic: Semantic,
+
+    /// The component data type.
+    pub data_type: DataType,
+
+    /// The dimensions of the attribute.
+    pub dimensions: Dimensions,
+
+    /// The offset in bytes of the attribute within a vertex.
+    pub offset: usize,
+}
+
+impl PrimitiveData {
+    /// Interleaves the vertex attributes, in the order positions, normals,
+    /// tangents, texture co-ordinates, colors, joints and weights.
+    ///
+    /// Morph targets and custom attributes are not included. Every
+    /// attribute is padded to a multiple of four bytes. Returns `None` if
+    /// any attribute has a different number of vertices than the positions.
+    pub fn interleave(&self) -> Option<Interleaved> {
+        let mut streams = Vec::<(Semantic, DataType, Dimensions, Vec<u8>)>::new();
+        streams.push((
+            Semantic::Positions,
+            DataType::F32,
+            Dimensions::Vec3,
+            f32_bytes(self.positions.iter().flatten()),
+        ));
+        if let Some(normals) = self.normals.as_ref() {
+            let bytes = f32_bytes(normals.iter().flatten());
+            streams.push((Semantic::Normals, DataType::F32, Dimensions::Vec3, bytes));
+        }
+        if let Some(tangents) = self.tangents.as_ref() {
+            let bytes = f32_bytes(tangents.iter().flatten());
+            streams.push((Semantic::Tangents, DataType::F32, Dimensions::Vec4, bytes));
+        }
+        for (set, tex_coords) in self.tex_coords.iter().enumerate() {
+            let semantic = Semantic::TexCoords(set as u32);
+            let bytes = f32_bytes(tex_coords.iter().flatten());
+            streams.push((semantic, DataType::F32, Dimensions::Vec2, bytes));
+        }
+        for (set, colors) in self.colors.iter().enumerate() {
+            let semantic = Semantic::Colors(set as u32);
+            let bytes = f32_bytes(colors.iter().flatten());
+            streams.push((semantic, DataType::F32, Dimensions::Vec4, bytes));
+        }
+        for (set, joints) in self.joints.iter().enumerate() {
+            let semantic = Semantic::Joints(set as u32);
+            let mut bytes = Vec::with_capacity(joints.len() * 8);
+            for joint in joints.iter().flatten() {
+                bytes.extend_from_slice(&joint.to_le_bytes());
+            }
+            streams.push((semantic, DataType::U16, Dimensions::Vec4, bytes));
+        }
+        for (set, weights) in self.weights.iter().enumerate() {
+            let semantic = Semantic::Weights(set as u32);
+            let bytes = f32_bytes(weights.iter().flatten());
+            streams.push((semantic, DataType::F32, Dimensions::Vec4, bytes));
+        }
+
+        let mut attributes = Vec::with_capacity(streams.len());
+        let mut stride = 0;
+        for (semantic, data_type, dimensions, bytes) in &streams {
+            let size = data_type.size() * dimensions.multiplicity();
+            if bytes.len() != size * self.positions.len() {
+                return None;
+            }
+            attributes.push(InterleavedAttribute {
+                semantic: semantic.clone(),
+                data_type: *data_type,
+                dimensions: *dimensions,
+                offset: stride,
+            });
+            stride += (size + 3) & !3;
+        }
+
+        let mut data = vec![0; stride * self.positions.len()];
+        for (attribute, (_, data_type, dimensions, bytes)) in attributes.iter().zip(&streams) {
+            let size = data_type.size() * dimensions.multiplicity();
+            let offset = attribute.offset;
+            for (vertex, bytes) in data.chunks_exact_mut(stride).zip(bytes.chunks_exact(size)) {
+                vertex[offset..offset + size].copy_from_slice(bytes);
+            }
+        }
+        Some(Interleaved {
+            stride,
+            attributes,
+            data,
+        })
+    }
+}
+
+/// Reads attribute sets from set `0` until the first missing set.
+pub(crate) fn read_sets<T>(mut read: impl FnMut(u32) -> Option<T>) -> Vec<T> {
+    let mut sets = Vec::new();
+    while let Some(set) = read(sets.len() as u32) {
+        sets.push(set);
+    }
+    sets
+}
+
+/// Reads an application-specific attribute in its stored type.
+///
+/// Matrices are read without the column padding required for some
+/// component types.
+#[cfg(feature = "extras")]
+pub(crate) fn read_custom<'a, 's, F>(
+    accessor: Accessor<'a>,
+    get_buffer_data: F,
+) -> Option<CustomAttribute>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    let dimensions = accessor.dimensions();
+    let normalized = accessor.normalized();
+    let data = match accessor.data_type() {
+        DataType::I8 => CustomData::I8(components(accessor, get_buffer_data)?),
+        DataType::U8 => CustomData::U8(components(accessor, get_buffer_data)?),
+        DataType::I16 => CustomData::I16(components(accessor, get_buffer_data)?),
+        DataType::U16 => CustomData::U16(components(accessor, get_buffer_data)?),
+        DataType::U32 => CustomData::U32(components(accessor, get_buffer_data)?),
+        DataType::F32 => CustomData::F32(components(accessor, get_buffer_data)?),
+    };
+    Some(CustomAttribute {
+        dimensions,
+        normalized,
+        data,
+    })
+}
+
+fn f32_bytes<'a>(values: impl Iterator<Item = &'a f32>) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for value in values {
+        bytes.extend_from_slice(&value.to_le_bytes());
+    }
+    bytes
+}
@@ -0,0 +1,70 @@
+/// Generated XYZ vertex normals.
+///
+/// This is an alias of `std::vec::IntoIter`, as the normals are computed
+/// up front rather than lazily.
+pub type GeneratedNormals = std::vec::IntoIter<[f32; 3]>;
+
+/// Computes the unit normal of each triangle.
+pub(crate) fn flat_normals(positions: &[[f32; 3]], triangles: &[[u32; 3]]) -> Vec<[f32; 3]> {
+    triangles
+        .iter()
+        .map(|triangle| normalize(face_normal(positions, triangle)))
+        .collect()
+}
+
+/// Computes per-vertex normals by averaging the normals of the triangles
+/// using each vertex, weighted by their area.
+pub(crate) fn smooth_normals(positions: &[[f32; 3]], triangles: &[[u32; 3]]) -> Vec<[f32; 3]> {
+    let mut normals = vec![[0.0; 3]; positions.len()];
+    for triangle in triangles {
+        // The cross product of two edges is as long as twice the area.
+        let normal = face_normal(positions, triangle);
+        for vertex in triangle {
+            if let Some(sum) = normals.get_mut(*vertex as usize) {
+                *sum = add(*sum, normal);
+            }
+        }
+    }
+    normals.into_iter().map(normalize).collect()
+}
+
+fn face_normal(positions: &[[f32; 3]], triangle: &[u32; 3]) -> [f32; 3] {
+    let vertex = |i: usize| positions.get(triangle[i] as usize).copied();
+    match (vertex(0), vertex(1), vertex(2)) {
+        (Some(a), Some(b), Some(c)) => cross(sub(b, a), sub(c, a)),
+        _ => [0.0; 3],
+    }
+}
+
+fn add(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn sub(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn scale(a: [f32; 3], s: f32) -> [f32; 3] {
+    [a[0] * s, a[1] * s, a[2] * s]
+}
+
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn normalize(a: [f32; 3]) -> [f32; 3] {
+    let length = dot(a, a).sqrt();
+    if length > 0.0 {
+        scale(a, length.recip())
+    } else {
+        a
+    }
+}
@@ -4,6 +4,15 @@ pub mod colors;
 /// Casting iterator adapters for vertex indices.
 pub mod indices;
 
+/// Morph target and skinning deformation.
+pub mod deform;
+
+/// Owned primitive extraction.
+pub mod extract;
+
+/// Normal generation.
+pub mod generate;
+
 /// Casting iterator adapters for joint indices.
 pub mod joints;
 
@@ -13,6 +22,9 @@ pub mod tex_coords;
 /// Casting iterator adapters for node weights.
 pub mod weights;
 
+/// Primitive topology conversion.
+pub(crate) mod topology;
+
 use crate::mesh;
 
 use crate::accessor::Iter;
@@ -20,6 +20,19 @@ pub struct U16;
 #[derive(Clone, Debug)]
 pub struct F32;
 
+/// The UV transform matrix that leaves texture co-ordinates unchanged.
+#[cfg(feature = "KHR_texture_transform")]
+pub(crate) const IDENTITY: [[f32; 3]; 3] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+/// Texture co-ordinates as f32 pairs with a texture transform applied.
+#[cfg(feature = "KHR_texture_transform")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_texture_transform")))]
+#[derive(Clone, Debug)]
+pub struct Transformed<'a> {
+    iter: CastingIter<'a, F32>,
+    matrix: [[f32; 3]; 3],
+}
+
 /// Trait for types which describe casting behaviour.
 pub trait Cast {
     /// Output type.
@@ -137,3 +150,34 @@ impl Cast for F32 {
         x.normalize()
     }
 }
+
+#[cfg(feature = "KHR_texture_transform")]
+impl<'a> Transformed<'a> {
+    pub(crate) fn new(iter: CastingIter<'a, F32>, matrix: [[f32; 3]; 3]) -> Self {
+        Self { iter, matrix }
+    }
+
+    /// Returns the column-major matrix applied to each pair of co-ordinates.
+    pub fn matrix(&self) -> [[f32; 3]; 3] {
+        self.matrix
+    }
+}
+
+#[cfg(feature = "KHR_texture_transform")]
+impl<'a> ExactSizeIterator for Transformed<'a> {}
+#[cfg(feature = "KHR_texture_transform")]
+impl<'a> Iterator for Transformed<'a> {
+    type Item = [f32; 2];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|uv| crate::texture::transform_uv(&self.matrix, uv))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -0,0 +1,79 @@
+use crate::mesh::Mode;
+use std::collections::HashSet;
+
+/// Assembles a triangle list from the vertex draw sequence of a triangle
+/// primitive, preserving the winding order of strips and fans.
+///
+/// Degenerate triangles, such as those used to join strips, are skipped.
+/// Returns `None` for point and line modes.
+pub(crate) fn triangles(mode: Mode, indices: &[u32]) -> Option<Vec<[u32; 3]>> {
+    let triangles = match mode {
+        Mode::Triangles => indices
+            .chunks_exact(3)
+            .map(|triangle| [triangle[0], triangle[1], triangle[2]])
+            .collect::<Vec<_>>(),
+        Mode::TriangleStrip => indices
+            .windows(3)
+            .enumerate()
+            .map(|(i, window)| {
+                if i % 2 == 0 {
+                    [window[0], window[1], window[2]]
+                } else {
+                    [window[0], window[2], window[1]]
+                }
+            })
+            .collect(),
+        Mode::TriangleFan => indices
+            .get(1..)
+            .unwrap_or(&[])
+            .windows(2)
+            .map(|window| [window[0], window[1], indices[0]])
+            .collect(),
+        _ => return None,
+    };
+    Some(
+        triangles
+            .into_iter()
+            .filter(|[a, b, c]| a != b && b != c && c != a)
+            .collect(),
+    )
+}
+
+/// Assembles a line list from the vertex draw sequence of a line primitive.
+///
+/// Returns `None` for point and triangle modes.
+pub(crate) fn lines(mode: Mode, indices: &[u32]) -> Option<Vec<[u32; 2]>> {
+    let strip = || indices.windows(2).map(|window| [window[0], window[1]]);
+    match mode {
+        Mode::Lines => Some(
+            indices
+                .chunks_exact(2)
+                .map(|line| [line[0], line[1]])
+                .collect(),
+        ),
+        Mode::LineStrip => Some(strip().collect()),
+        Mode::LineLoop => {
+            let mut lines = strip().collect::<Vec<_>>();
+            if let [first, _, .., last] = indices {
+                lines.push([*last, *first]);
+            }
+            Some(lines)
+        }
+        _ => None,
+    }
+}
+
+/// Collects the unique edges of a triangle list, in the order in which they
+/// are first used.
+pub(crate) fn edges(triangles: &[[u32; 3]]) -> Vec<[u32; 2]> {
+    let mut visited = HashSet::new();
+    let mut edges = Vec::new();
+    for [a, b, c] in triangles {
+        for edge in [[*a, *b], [*b, *c], [*c, *a]].iter() {
+            if visited.insert([edge[0].min(edge[1]), edge[0].max(edge[1])]) {
+                edges.push(*edge);
+            }
+        }
+    }
+    edges
+}
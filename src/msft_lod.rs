@@ -0,0 +1,58 @@
+/// A level of detail in an `MSFT_lod` chain.
+#[derive(Clone, Debug)]
+pub struct Level<T> {
+    /// The node or material to use at this level.
+    pub object: T,
+
+    /// The screen coverage threshold for this level, as given by the
+    /// `MSFT_screencoverage` array in the extras of the object that owns the
+    /// chain.
+    ///
+    /// A level should be used while the object's bounding volume covers at
+    /// least this fraction of the screen.
+    #[cfg(feature = "extras")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extras")))]
+    pub screen_coverage: Option<f32>,
+}
+
+/// Builds an LOD chain, pairing each level with its screen coverage threshold
+/// when the `extras` feature is enabled.
+#[cfg_attr(not(feature = "extras"), allow(unused_variables))]
+pub(crate) fn chain<T>(
+    base: T,
+    lower_levels: impl Iterator<Item = T>,
+    extras: &json::Extras,
+) -> Vec<Level<T>> {
+    #[cfg(feature = "extras")]
+    let mut coverages = screen_coverages(extras).into_iter();
+    std::iter::once(base)
+        .chain(lower_levels)
+        .map(|object| Level {
+            object,
+            #[cfg(feature = "extras")]
+            screen_coverage: coverages.next(),
+        })
+        .collect()
+}
+
+/// Reads the `MSFT_screencoverage` array from an object's extras.
+#[cfg(feature = "extras")]
+fn screen_coverages(extras: &json::Extras) -> Vec<f32> {
+    let value = match extras {
+        Some(raw) => json::deserialize::from_str::<json::Value>(raw.get()).ok(),
+        None => None,
+    };
+    value
+        .as_ref()
+        .and_then(|value| value.get("MSFT_screencoverage"))
+        .and_then(|coverages| coverages.as_array())
+        .map(|coverages| {
+            coverages
+                .iter()
+                .map(|coverage| coverage.as_f64().map(|x| x as f32))
+                .take_while(Option::is_some)
+                .flatten()
+                .collect()
+        })
+        .unwrap_or_default()
+}
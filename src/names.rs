@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+
+/// Indices of the objects sharing each name within one top-level array.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Table(HashMap<String, Vec<usize>>);
+
+/// Indices of the named top-level objects of a document.
+#[derive(Clone, Debug)]
+pub(crate) struct Names {
+    pub(crate) accessors: Table,
+    pub(crate) animations: Table,
+    pub(crate) buffers: Table,
+    pub(crate) buffer_views: Table,
+    pub(crate) cameras: Table,
+    pub(crate) images: Table,
+    pub(crate) materials: Table,
+    pub(crate) meshes: Table,
+    pub(crate) nodes: Table,
+    pub(crate) samplers: Table,
+    pub(crate) scenes: Table,
+    pub(crate) skins: Table,
+    pub(crate) textures: Table,
+}
+
+impl Table {
+    /// Indexes the names of the objects of one top-level array.
+    fn new<'a>(names: impl Iterator<Item = &'a Option<String>>) -> Self {
+        let mut map = HashMap::<String, Vec<usize>>::new();
+        for (index, name) in names.enumerate() {
+            if let Some(name) = name {
+                map.entry(name.clone()).or_default().push(index);
+            }
+        }
+        Table(map)
+    }
+
+    /// Returns the index of the first object named `name`.
+    pub(crate) fn get(&self, name: &str) -> Option<usize> {
+        self.0.get(name)?.first().copied()
+    }
+}
+
+impl Names {
+    /// Indexes the names of the top-level objects of a document.
+    pub(crate) fn new(root: &json::Root) -> Self {
+        Names {
+            accessors: Table::new(root.accessors.iter().map(|x| &x.name)),
+            animations: Table::new(root.animations.iter().map(|x| &x.name)),
+            buffers: Table::new(root.buffers.iter().map(|x| &x.name)),
+            buffer_views: Table::new(root.buffer_views.iter().map(|x| &x.name)),
+            cameras: Table::new(root.cameras.iter().map(|x| &x.name)),
+            images: Table::new(root.images.iter().map(|x| &x.name)),
+            materials: Table::new(root.materials.iter().map(|x| &x.name)),
+            meshes: Table::new(root.meshes.iter().map(|x| &x.name)),
+            nodes: Table::new(root.nodes.iter().map(|x| &x.name)),
+            samplers: Table::new(root.samplers.iter().map(|x| &x.name)),
+            scenes: Table::new(root.scenes.iter().map(|x| &x.name)),
+            skins: Table::new(root.skins.iter().map(|x| &x.name)),
+            textures: Table::new(root.textures.iter().map(|x| &x.name)),
+        }
+    }
+
+    /// Returns every name shared by several objects of the same array, with
+    /// the paths of those objects.
+    ///
+    /// The names are borrowed from `root`, which must be the JSON these
+    /// tables were built from.
+    pub(crate) fn duplicates<'a>(&self, root: &'a json::Root) -> Vec<(&'a str, Vec<json::Path>)> {
+        type Name = fn(&json::Root, usize) -> &Option<String>;
+        let tables: [(&str, &Table, Name); 13] = [
+            ("accessors", &self.accessors, |root, i| {
+                &root.accessors[i].name
+            }),
+            ("animations", &self.animations, |root, i| {
+                &root.animations[i].name
+            }),
+            ("buffers", &self.buffers, |root, i| &root.buffers[i].name),
+            ("bufferViews", &self.buffer_views, |root, i| {
+                &root.buffer_views[i].name
+            }),
+            ("cameras", &self.cameras, |root, i| &root.cameras[i].name),
+            ("images", &self.images, |root, i| &root.images[i].name),
+            ("materials", &self.materials, |root, i| {
+                &root.materials[i].name
+            }),
+            ("meshes", &self.meshes, |root, i| &root.meshes[i].name),
+            ("nodes", &self.nodes, |root, i| &root.nodes[i].name),
+            ("samplers", &self.samplers, |root, i| &root.samplers[i].name),
+            ("scenes", &self.scenes, |root, i| &root.scenes[i].name),
+            ("skins", &self.skins, |root, i| &root.skins[i].name),
+            ("textures", &self.textures, |root, i| &root.textures[i].name),
+        ];
+        let mut duplicates = Vec::new();
+        for (array, table, name) in tables.iter() {
+            let mut shared = table
+                .0
+                .values()
+                .filter(|indices| indices.len() > 1)
+                .collect::<Vec<_>>();
+            shared.sort_by_key(|indices| indices[0]);
+            for indices in shared {
+                let paths = indices
+                    .iter()
+                    .map(|index| json::Path::new().field(array).index(*index))
+                    .collect();
+                if let Some(name) = name(root, indices[0]) {
+                    duplicates.push((name.as_str(), paths));
+                }
+            }
+        }
+        duplicates
+    }
+}
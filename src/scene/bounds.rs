@@ -0,0 +1,152 @@
+use crate::mesh::BoundingBox;
+use crate::scene::iter::Traverse;
+use crate::{Node, Scene};
+
+#[cfg(feature = "utils")]
+use crate::math::Matrix4;
+#[cfg(feature = "utils")]
+use crate::mesh::util::deform::Deformed;
+#[cfg(feature = "utils")]
+use crate::scene::WorldNode;
+#[cfg(feature = "utils")]
+use crate::Buffer;
+
+impl<'a> Node<'a> {
+    /// Returns the world-space bounds of the meshes of this node and its
+    /// descendants, computed by transforming the accessor bounds of their
+    /// primitives, including morph target extents.
+    ///
+    /// Skinned meshes are placed by the transform of their node, which only
+    /// approximates their skinned extents. Returns `None` if no mesh is
+    /// found.
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        bounding_box(self.traverse())
+    }
+
+    /// Returns the world-space bounds of the vertices of the meshes of this
+    /// node and its descendants.
+    ///
+    /// Morph targets are applied with the default weights of the node or
+    /// mesh, and skinned meshes are posed by the current world transforms of
+    /// their joints. Returns `None` if no vertex positions can be read.
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn tight_bounding_box<'s, F>(&self, get_buffer_data: F) -> Option<BoundingBox>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        tight_bounding_box(self.traverse(), get_buffer_data)
+    }
+}
+
+impl<'a> Scene<'a> {
+    /// Returns the world-space bounds of the meshes of the scene, computed by
+    /// transforming the accessor bounds of their primitives, including morph
+    /// target extents.
+    ///
+    /// Skinned meshes are placed by the transform of their node, which only
+    /// approximates their skinned extents. Returns `None` if the scene has no
+    /// meshes.
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        bounding_box(self.traverse())
+    }
+
+    /// Returns the world-space bounds of the vertices of the meshes of the
+    /// scene.
+    ///
+    /// Morph targets are applied with the default weights of the node or
+    /// mesh, and skinned meshes are posed by the current world transforms of
+    /// their joints. Returns `None` if no vertex positions can be read.
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn tight_bounding_box<'s, F>(&self, get_buffer_data: F) -> Option<BoundingBox>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        tight_bounding_box(self.traverse(), get_buffer_data)
+    }
+}
+
+fn bounding_box(traverse: Traverse) -> Option<BoundingBox> {
+    traverse
+        .filter_map(|world_node| {
+            let bounds = world_node.node.mesh()?.bounding_box()?;
+            Some(bounds.transform(&world_node.matrix))
+        })
+        .fold(None, |union: Option<BoundingBox>, bounds| match union {
+            Some(union) => Some(union.union(&bounds)),
+            None => Some(bounds),
+        })
+}
+
+#[cfg(feature = "utils")]
+fn tight_bounding_box<'a, 's, F>(traverse: Traverse<'a>, get_buffer_data: F) -> Option<BoundingBox>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    let mut bounds = None;
+    for world_node in traverse {
+        extend_with_vertices(&mut bounds, &world_node, &get_buffer_data);
+    }
+    bounds
+}
+
+/// Grows `bounds` to contain the world-space vertex positions of the mesh of
+/// a node.
+#[cfg(feature = "utils")]
+fn extend_with_vertices<'a, 's, F>(
+    bounds: &mut Option<BoundingBox>,
+    world_node: &WorldNode<'a>,
+    get_buffer_data: &F,
+) where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    let node = &world_node.node;
+    let mesh = match node.mesh() {
+        Some(mesh) => mesh,
+        None => return,
+    };
+    let weights = node.weights().or_else(|| mesh.weights()).unwrap_or(&[]);
+    let joint_matrices = node.skin().and_then(|skin| {
+        let matrices = skin.global_joint_matrices(|joint| joint.world_matrix(), get_buffer_data)?;
+        Some(matrices.iter().map(Matrix4::as_array).collect::<Vec<_>>())
+    });
+    let world_matrix = Matrix4::from_array(world_node.matrix);
+    let mut extend = |point: [f32; 3]| match bounds.as_mut() {
+        Some(bounds) => bounds.extend(point),
+        None => {
+            *bounds = Some(BoundingBox {
+                min: point,
+                max: point,
+            })
+        }
+    };
+
+    for primitive in mesh.primitives() {
+        let reader = primitive.reader(get_buffer_data.clone());
+        let positions = match reader.read_positions() {
+            Some(positions) => positions.collect(),
+            None => continue,
+        };
+        let deformed = Deformed {
+            positions,
+            ..Deformed::default()
+        };
+        // Skinned vertices are already in world space, since the joint
+        // matrices are not made relative to the mesh node. Vertices without
+        // any influence are moved there by the world matrix of the node.
+        let deformed = reader.deform(
+            deformed,
+            weights,
+            joint_matrices.as_deref(),
+            Some(&world_node.matrix),
+        );
+        for position in deformed.positions {
+            if joint_matrices.is_some() {
+                extend(position);
+            } else {
+                extend(world_matrix.transform_point(position));
+            }
+        }
+    }
+}
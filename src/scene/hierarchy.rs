@@ -0,0 +1,57 @@
+/// Parent links and scene membership of every node, derived from the
+/// `children` and `scene.nodes` arrays.
+#[derive(Clone, Debug)]
+pub(crate) struct Hierarchy {
+    /// The parent of each node, indexed by node.
+    pub(crate) parents: Vec<Option<usize>>,
+
+    /// The first scene containing each node, indexed by node.
+    pub(crate) scenes: Vec<Option<usize>>,
+}
+
+impl Hierarchy {
+    /// Builds the hierarchy of a document.
+    ///
+    /// In malformed documents where a node is listed as a child more than
+    /// once, the first parent found wins. Out of range node indices are
+    /// ignored, since the document may not have been validated.
+    pub(crate) fn new(root: &json::Root) -> Self {
+        let count = root.nodes.len();
+        let children = |node: usize| {
+            root.nodes[node]
+                .children
+                .iter()
+                .flatten()
+                .map(|child| child.value())
+                .filter(|child| *child < count)
+        };
+
+        let mut parents = vec![None; count];
+        for node in 0..count {
+            for child in children(node) {
+                let parent = &mut parents[child];
+                if parent.is_none() && child != node {
+                    *parent = Some(node);
+                }
+            }
+        }
+
+        let mut scenes = vec![None; count];
+        for (index, scene) in root.scenes.iter().enumerate() {
+            let mut stack = scene
+                .nodes
+                .iter()
+                .map(|node| node.value())
+                .filter(|node| *node < count)
+                .collect::<Vec<_>>();
+            while let Some(node) = stack.pop() {
+                if scenes[node].is_none() {
+                    scenes[node] = Some(index);
+                    stack.extend(children(node));
+                }
+            }
+        }
+
+        Self { parents, scenes }
+    }
+}
@@ -1,5 +1,7 @@
 use std::slice;
 
+use crate::math::Matrix4;
+use crate::scene::WorldNode;
 use crate::{Document, Node};
 
 /// An `Iterator` that visits the nodes in a scene.
@@ -22,6 +24,54 @@ pub struct Children<'a> {
     pub(crate) iter: slice::Iter<'a, json::Index<json::scene::Node>>,
 }
 
+/// An `Iterator` that visits the ancestors of a node.
+#[derive(Clone, Debug)]
+pub struct Ancestors<'a> {
+    /// The next ancestor to visit.
+    pub(crate) next: Option<Node<'a>>,
+
+    /// The maximum number of ancestors left, guarding against cycles in
+    /// malformed input.
+    pub(crate) remaining: usize,
+}
+
+/// An `Iterator` that visits the nodes of a scene depth-first, parents before
+/// their children, together with their world transforms.
+#[derive(Clone, Debug)]
+pub struct Traverse<'a> {
+    /// Nodes yet to be visited, with their parent and its world transform.
+    stack: Vec<(Node<'a>, Option<Node<'a>>, Matrix4)>,
+
+    /// Nodes already visited.
+    visited: Vec<bool>,
+}
+
+impl<'a> Traverse<'a> {
+    /// Constructs a traversal starting at the given root nodes.
+    pub(crate) fn new(document: &'a Document, roots: Nodes<'a>) -> Self {
+        let identity = Matrix4::identity();
+        let mut stack = roots.map(|node| (node, None, identity)).collect::<Vec<_>>();
+        stack.reverse();
+        Self {
+            stack,
+            visited: vec![false; document.nodes().len()],
+        }
+    }
+
+    /// Constructs a traversal of a node and its descendants.
+    pub(crate) fn subtree(node: Node<'a>) -> Self {
+        let parent = node.parent();
+        let parent_matrix = match parent.as_ref() {
+            Some(parent) => Matrix4::from_array(parent.world_matrix()),
+            None => Matrix4::identity(),
+        };
+        Self {
+            visited: vec![false; node.document.nodes().len()],
+            stack: vec![(node, parent, parent_matrix)],
+        }
+    }
+}
+
 impl<'a> ExactSizeIterator for Nodes<'a> {}
 impl<'a> Iterator for Nodes<'a> {
     type Item = Node<'a>;
@@ -62,3 +112,42 @@ impl<'a> Iterator for Children<'a> {
             .map(|index| self.document.nodes().nth(index.value()).unwrap())
     }
 }
+
+impl<'a> Iterator for Traverse<'a> {
+    type Item = WorldNode<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((node, parent, parent_matrix)) = self.stack.pop() {
+            // A node may only appear once in a scene; skip repeated references
+            // rather than visiting a subtree twice or looping forever.
+            if std::mem::replace(&mut self.visited[node.index()], true) {
+                continue;
+            }
+            let matrix = parent_matrix * Matrix4::from_array(node.transform().matrix());
+            let start = self.stack.len();
+            self.stack.extend(
+                node.children()
+                    .map(|child| (child, Some(node.clone()), matrix)),
+            );
+            self.stack[start..].reverse();
+            return Some(WorldNode {
+                node,
+                parent,
+                matrix: matrix.as_array(),
+            });
+        }
+        None
+    }
+}
+
+impl<'a> Iterator for Ancestors<'a> {
+    type Item = Node<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let node = self.next.take()?;
+        self.next = node.parent();
+        Some(node)
+    }
+}
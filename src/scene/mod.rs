@@ -1,6 +1,12 @@
 use crate::math::*;
 use crate::{Camera, Document, Mesh, Skin};
 
+/// World-space bounds of nodes and scenes.
+mod bounds;
+
+/// Parent links of the node hierarchy.
+pub(crate) mod hierarchy;
+
 /// Iterators.
 pub mod iter;
 
@@ -97,11 +103,23 @@ pub struct Node<'a> {
     json: &'a json::scene::Node,
 }
 
+/// A node visited by a scene traversal, with its parent and world transform.
+#[derive(Clone, Debug)]
+pub struct WorldNode<'a> {
+    /// The visited node.
+    pub(crate) node: Node<'a>,
+
+    /// The node that lists the visited node as a child.
+    pub(crate) parent: Option<Node<'a>>,
+
+    /// The column-major world transform of the node.
+    pub(crate) matrix: [[f32; 4]; 4],
+}
+
 /// The root nodes of a scene.
 #[derive(Clone, Debug)]
 pub struct Scene<'a> {
     /// The parent `Document` struct.
-    #[allow(dead_code)]
     document: &'a Document,
 
     /// The corresponding JSON index.
@@ -134,6 +152,32 @@ impl<'a> Node<'a> {
             .map(|index| self.document.cameras().nth(index.value()).unwrap())
     }
 
+    /// Returns the node that lists this node as a child, if any.
+    pub fn parent(&self) -> Option<Node<'a>> {
+        let index = self.document.hierarchy().parents[self.index]?;
+        self.document.nodes().nth(index)
+    }
+
+    /// Returns an `Iterator` that visits the ancestors of this node, starting
+    /// with its parent and ending with a node without a parent.
+    pub fn ancestors(&self) -> iter::Ancestors<'a> {
+        iter::Ancestors {
+            next: self.parent(),
+            remaining: self.document.nodes().len(),
+        }
+    }
+
+    /// Returns the column-major view matrix of the camera referenced by this
+    /// node, the inverse of the node's world transform.
+    ///
+    /// Returns `None` if the node has no camera or its world transform is
+    /// not invertible.
+    pub fn view_matrix(&self) -> Option<[[f32; 4]; 4]> {
+        self.camera()?;
+        let world = Matrix4::from_array(self.world_matrix());
+        world.inverse().map(|view| view.as_array())
+    }
+
     /// Returns an `Iterator` that visits the node's children.
     pub fn children(&self) -> iter::Children<'a> {
         iter::Children {
@@ -147,6 +191,35 @@ impl<'a> Node<'a> {
         &self.json.extras
     }
 
+    /// Returns the XMP metadata packet of this node as defined by the
+    /// `KHR_xmp_json_ld` extension.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_xmp_json_ld")))]
+    pub fn xmp_packet(&self) -> Option<crate::khr_xmp_json_ld::Packet<'a>> {
+        self.document
+            .xmp_packet(self.json.extensions.as_ref()?.khr_xmp_json_ld.as_ref())
+    }
+
+    /// Returns the per-instance attributes of this node as defined by the
+    /// `EXT_mesh_gpu_instancing` extension.
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_mesh_gpu_instancing")))]
+    pub fn mesh_gpu_instancing(
+        &self,
+    ) -> Option<crate::ext_mesh_gpu_instancing::MeshGpuInstancing<'a>> {
+        let json = self
+            .json
+            .extensions
+            .as_ref()?
+            .ext_mesh_gpu_instancing
+            .as_ref()?;
+        Some(crate::ext_mesh_gpu_instancing::MeshGpuInstancing::new(
+            self.document,
+            self.clone(),
+            json,
+        ))
+    }
+
     /// Returns the light at this node as defined by the `KHR_lights_punctual` extension.
     #[cfg(feature = "KHR_lights_punctual")]
     #[cfg_attr(docsrs, doc(cfg(feature = "KHR_lights_punctual")))]
@@ -163,6 +236,26 @@ impl<'a> Node<'a> {
         }
     }
 
+    /// Returns the levels of detail of this node as defined by the `MSFT_lod`
+    /// extension, ordered from highest to lowest detail.
+    ///
+    /// The first level is always this node. Nodes without the extension have
+    /// a single level.
+    #[cfg(feature = "MSFT_lod")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "MSFT_lod")))]
+    pub fn lod_chain(&self) -> Vec<crate::msft_lod::Level<Node<'a>>> {
+        let ids = self
+            .json
+            .extensions
+            .as_ref()
+            .and_then(|extensions| extensions.msft_lod.as_ref())
+            .map_or(&[][..], |lod| lod.ids.as_slice());
+        let nodes = ids
+            .iter()
+            .map(|index| self.document.nodes().nth(index.value()).unwrap());
+        crate::msft_lod::chain(self.clone(), nodes, &self.json.extras)
+    }
+
     /// Returns the mesh referenced by this node.
     pub fn mesh(&self) -> Option<Mesh<'a>> {
         self.json
@@ -177,6 +270,38 @@ impl<'a> Node<'a> {
         self.json.name.as_deref()
     }
 
+    /// Returns the slash-separated names of the node and its ancestors,
+    /// starting from the node without a parent, such as `Root/Arm/Hand`.
+    ///
+    /// Returns `None` if the node or any of its ancestors is unnamed.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn path(&self) -> Option<String> {
+        let mut names = std::iter::once(self.clone())
+            .chain(self.ancestors())
+            .map(|node| node.name())
+            .collect::<Option<Vec<_>>>()?;
+        names.reverse();
+        Some(names.join("/"))
+    }
+
+    /// Returns the world transform of the node as a column-major matrix,
+    /// accumulated from the transforms of its ancestors.
+    pub fn world_matrix(&self) -> [[f32; 4]; 4] {
+        let mut matrix = Matrix4::from_array(self.transform().matrix());
+        for ancestor in self.ancestors() {
+            matrix = Matrix4::from_array(ancestor.transform().matrix()) * matrix;
+        }
+        matrix.as_array()
+    }
+
+    /// Returns an `Iterator` that visits this node and its descendants
+    /// depth-first, parents before their children, with their parent and
+    /// world transform.
+    pub fn traverse(&self) -> iter::Traverse<'a> {
+        iter::Traverse::subtree(self.clone())
+    }
+
     /// Returns the node's transform.
     pub fn transform(&self) -> Transform {
         if let Some(m) = self.json.matrix {
@@ -211,6 +336,23 @@ impl<'a> Node<'a> {
     }
 }
 
+impl<'a> WorldNode<'a> {
+    /// Returns the visited node.
+    pub fn node(&self) -> Node<'a> {
+        self.node.clone()
+    }
+
+    /// Returns the parent of the node, or `None` for a root node of the scene.
+    pub fn parent(&self) -> Option<Node<'a>> {
+        self.parent.clone()
+    }
+
+    /// Returns the world transform of the node as a column-major matrix.
+    pub fn matrix(&self) -> [[f32; 4]; 4] {
+        self.matrix
+    }
+}
+
 impl<'a> Scene<'a> {
     /// Constructs a `Scene`.
     pub(crate) fn new(document: &'a Document, index: usize, json: &'a json::scene::Scene) -> Self {
@@ -231,12 +373,57 @@ impl<'a> Scene<'a> {
         &self.json.extras
     }
 
+    /// Returns the XMP metadata packet of this scene as defined by the
+    /// `KHR_xmp_json_ld` extension.
+    #[cfg(feature = "KHR_xmp_json_ld")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_xmp_json_ld")))]
+    pub fn xmp_packet(&self) -> Option<crate::khr_xmp_json_ld::Packet<'a>> {
+        self.document
+            .xmp_packet(self.json.extensions.as_ref()?.khr_xmp_json_ld.as_ref())
+    }
+
+    /// Returns the environment light of this scene as defined by the
+    /// `EXT_lights_image_based` extension.
+    ///
+    /// Returns `None` if the scene has no environment light, or if the light
+    /// it references is not defined by the document.
+    #[cfg(feature = "EXT_lights_image_based")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_lights_image_based")))]
+    pub fn image_based_light(&self) -> Option<crate::ext_lights_image_based::ImageBasedLight<'a>> {
+        let index = self
+            .json
+            .extensions
+            .as_ref()?
+            .ext_lights_image_based
+            .as_ref()?
+            .light;
+        self.document.image_based_lights()?.nth(index.value())
+    }
+
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&'a str> {
         self.json.name.as_deref()
     }
 
+    /// Returns an `Iterator` that visits every node of the scene depth-first,
+    /// parents before their children, with its parent and world transform.
+    ///
+    /// Nodes referenced more than once are only visited the first time.
+    pub fn traverse(&self) -> iter::Traverse<'a> {
+        iter::Traverse::new(self.document, self.nodes())
+    }
+
+    /// Returns an `Iterator` that visits every light of the scene with its
+    /// world-space placement, computed from the node hierarchy.
+    #[cfg(feature = "KHR_lights_punctual")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_lights_punctual")))]
+    pub fn light_instances(&self) -> crate::khr_lights_punctual::LightInstances<'a> {
+        crate::khr_lights_punctual::LightInstances {
+            traverse: self.traverse(),
+        }
+    }
+
     /// Returns an `Iterator` that visits each root node of the scene.
     pub fn nodes(&self) -> iter::Nodes<'a> {
         iter::Nodes {
@@ -246,6 +433,25 @@ impl<'a> Scene<'a> {
     }
 }
 
+/// Finds the first node matching a path of names, depth-first, starting with
+/// the given candidates for the first name.
+#[cfg(feature = "names")]
+pub(crate) fn find_path<'a>(
+    candidates: impl Iterator<Item = Node<'a>>,
+    names: &[&str],
+) -> Option<Node<'a>> {
+    let (name, rest) = names.split_first()?;
+    for candidate in candidates.filter(|node| node.name() == Some(*name)) {
+        if rest.is_empty() {
+            return Some(candidate);
+        }
+        if let Some(node) = find_path(candidate.children(), rest) {
+            return Some(node);
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use crate::math::*;
@@ -1,5 +1,7 @@
 use crate::{Accessor, Document, Node};
 
+#[cfg(feature = "utils")]
+use crate::math::Matrix4;
 #[cfg(feature = "utils")]
 use crate::Buffer;
 
@@ -62,7 +64,7 @@ impl<'a> Skin<'a> {
     /// Constructs a skin reader.
     #[cfg(feature = "utils")]
     #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
-    pub fn reader<'s, F>(&'a self, get_buffer_data: F) -> Reader<'a, 's, F>
+    pub fn reader<'s, F>(&self, get_buffer_data: F) -> Reader<'a, 's, F>
     where
         F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
     {
@@ -72,6 +74,69 @@ impl<'a> Skin<'a> {
         }
     }
 
+    /// Returns the joint matrices used to skin the mesh of `mesh_node`, given
+    /// the world transform of each node.
+    ///
+    /// Each matrix is the inverse world transform of the mesh node, times
+    /// the world transform of the joint, times its inverse bind matrix. The
+    /// identity is used for the inverse bind matrices when the skin has none.
+    /// Use `Node::world_matrix` for the rest pose, or the world transforms
+    /// of an animation `Pose`.
+    ///
+    /// Returns `None` if the inverse bind matrices cannot be read or the
+    /// world transform of the mesh node is not invertible.
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn joint_matrices<'s, F, W>(
+        &self,
+        mesh_node: &Node<'a>,
+        world_matrix: W,
+        get_buffer_data: F,
+    ) -> Option<Vec<[[f32; 4]; 4]>>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+        W: Fn(&Node<'a>) -> [[f32; 4]; 4],
+    {
+        let inverse_mesh_matrix = Matrix4::from_array(world_matrix(mesh_node)).inverse()?;
+        let matrices = self.global_joint_matrices(world_matrix, &get_buffer_data)?;
+        Some(
+            matrices
+                .into_iter()
+                .map(|matrix| (inverse_mesh_matrix * matrix).as_array())
+                .collect(),
+        )
+    }
+
+    /// Returns the world transform of each joint multiplied by its inverse
+    /// bind matrix.
+    #[cfg(feature = "utils")]
+    pub(crate) fn global_joint_matrices<'s, F, W>(
+        &self,
+        world_matrix: W,
+        get_buffer_data: &F,
+    ) -> Option<Vec<Matrix4>>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+        W: Fn(&Node<'a>) -> [[f32; 4]; 4],
+    {
+        let inverse_bind_matrices = match self.inverse_bind_matrices() {
+            Some(_) => self
+                .reader(get_buffer_data.clone())
+                .read_inverse_bind_matrices()?
+                .map(Matrix4::from_array)
+                .collect::<Vec<_>>(),
+            None => vec![Matrix4::identity(); self.joints().len()],
+        };
+        Some(
+            self.joints()
+                .zip(inverse_bind_matrices)
+                .map(|(joint, inverse_bind_matrix)| {
+                    Matrix4::from_array(world_matrix(&joint)) * inverse_bind_matrix
+                })
+                .collect(),
+        )
+    }
+
     /// Returns an `Iterator` that visits the skeleton nodes used as joints in
     /// this skin.
     pub fn joints(&self) -> iter::Joints<'a> {
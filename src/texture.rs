@@ -186,6 +186,16 @@ impl<'a> Info<'a> {
             .map(TextureTransform::new)
     }
 
+    /// Returns the set index of the texture co-ordinates used to sample the
+    /// texture, taking the `KHR_texture_transform` override into account.
+    #[cfg(feature = "KHR_texture_transform")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_texture_transform")))]
+    pub fn effective_tex_coord(&self) -> u32 {
+        self.texture_transform()
+            .and_then(|transform| transform.tex_coord())
+            .unwrap_or_else(|| self.tex_coord())
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &json::Extras {
         &self.json.extras
@@ -240,8 +250,35 @@ impl<'a> TextureTransform<'a> {
         self.json.tex_coord
     }
 
+    /// Returns the column-major 3x3 matrix that transforms texture
+    /// co-ordinates, equal to `translation * rotation * scale`.
+    pub fn matrix(&self) -> [[f32; 3]; 3] {
+        let [x, y] = self.offset();
+        let [sx, sy] = self.scale();
+        let (sin, cos) = self.rotation().sin_cos();
+        [
+            [cos * sx, -sin * sx, 0.0],
+            [sin * sy, cos * sy, 0.0],
+            [x, y, 1.0],
+        ]
+    }
+
+    /// Applies the transform to a pair of texture co-ordinates.
+    pub fn apply(&self, uv: [f32; 2]) -> [f32; 2] {
+        transform_uv(&self.matrix(), uv)
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &json::Extras {
         &self.json.extras
     }
 }
+
+/// Multiplies texture co-ordinates by a column-major UV transform matrix.
+#[cfg(feature = "KHR_texture_transform")]
+pub(crate) fn transform_uv(matrix: &[[f32; 3]; 3], [u, v]: [f32; 2]) -> [f32; 2] {
+    [
+        matrix[0][0] * u + matrix[1][0] * v + matrix[2][0],
+        matrix[0][1] * u + matrix[1][1] * v + matrix[2][1],
+    ]
+}
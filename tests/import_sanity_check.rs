@@ -1,3 +1,5 @@
+#![cfg(feature = "import")]
+
 use std::error::Error as StdError;
 use std::{fs, path};
 
@@ -22,3 +22,1082 @@ fn test_accessor_bounds() {
         }
     );
 }
+
+#[cfg(all(
+    feature = "KHR_animation_pointer",
+    feature = "KHR_lights_punctual",
+    feature = "import",
+    feature = "utils"
+))]
+#[test]
+fn test_animation_pointer() {
+    use gltf::animation::util::{sampling::Value, PointerValues};
+    use gltf::khr_animation_pointer::{LightProperty, MaterialProperty, NodeProperty, Pointer};
+
+    let (document, buffers, _) = gltf::import("tests/animation_pointer.gltf").unwrap();
+    let animation = document.animations().next().unwrap();
+    let channels = animation.channels().collect::<Vec<_>>();
+
+    match channels[0].pointer() {
+        Some(Pointer::Material(material, MaterialProperty::BaseColorFactor)) => {
+            assert_eq!(material.index(), Some(0))
+        }
+        other => panic!("unexpected target {:?}", other),
+    }
+    match channels[1].pointer() {
+        Some(Pointer::Light(light, LightProperty::Intensity)) => assert_eq!(light.index(), 0),
+        other => panic!("unexpected target {:?}", other),
+    }
+    match channels[2].pointer() {
+        Some(Pointer::Other(pointer)) => assert_eq!(
+            pointer,
+            "/materials/0/extensions/KHR_materials_emissive_strength/emissiveStrength"
+        ),
+        other => panic!("unexpected target {:?}", other),
+    }
+    match channels[3].pointer() {
+        Some(Pointer::Node(node, NodeProperty::Weights)) => assert_eq!(node.index(), 0),
+        other => panic!("unexpected target {:?}", other),
+    }
+    assert!(channels[0].target().pointer().is_some());
+    assert_eq!(channels[3].target().node().unwrap().index(), 0);
+    assert!(channels[0].target().node().is_none());
+    assert!(channels[0].target().property().is_none());
+
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&*buffers[buffer.index()]);
+    let reader = channels[0].reader(get_buffer_data);
+    assert!(reader.read_outputs().is_none());
+    match reader.read_pointer_outputs() {
+        Some(PointerValues::Vec4(colors)) => assert_eq!(
+            colors.collect::<Vec<_>>(),
+            [[1.0, 0.0, 0.0, 1.0], [0.0, 0.0, 1.0, 1.0]]
+        ),
+        _ => panic!("expected vec4 pointer outputs"),
+    }
+
+    // Normalized integer outputs are converted to `f32`.
+    let keyframes = channels[2]
+        .reader(get_buffer_data)
+        .read_keyframes()
+        .unwrap();
+    assert_eq!(keyframes.property(), None);
+    assert_eq!(keyframes.outputs(), [0.0, 1.0]);
+    assert_eq!(keyframes.sample(0.5), Value::Components(vec![0.5]));
+}
+
+#[cfg(all(
+    feature = "EXT_mesh_gpu_instancing",
+    feature = "import",
+    feature = "utils"
+))]
+#[test]
+fn test_mesh_gpu_instancing() {
+    let (document, buffers, _) = gltf::import("tests/mesh_gpu_instancing.gltf").unwrap();
+    let node = document.nodes().next().unwrap();
+    let instancing = node.mesh_gpu_instancing().unwrap();
+    assert_eq!(instancing.count(), 2);
+    assert_eq!(instancing.attributes().count(), 3);
+
+    let reader = instancing.reader(|buffer| Some(&buffers[buffer.index()]));
+    let transforms = reader
+        .read_transforms()
+        .unwrap()
+        .map(|transform| transform.decomposed())
+        .collect::<Vec<_>>();
+    assert_eq!(transforms.len(), 2);
+    assert_eq!(
+        transforms[0],
+        ([1.0, 0.0, 0.0], [0.0, 0.0, 0.0, 1.0], [1.0, 1.0, 1.0])
+    );
+
+    let (translation, rotation, scale) = transforms[1];
+    assert_eq!(translation, [0.0, 2.0, 0.0]);
+    assert_eq!(scale, [2.0, 3.0, 4.0]);
+    let half_sqrt_2 = std::f32::consts::FRAC_1_SQRT_2;
+    assert!((rotation[1] - half_sqrt_2).abs() < 1e-4);
+    assert!((rotation[3] - half_sqrt_2).abs() < 1e-4);
+
+    let matrix = reader.read_matrices().unwrap().nth(1).unwrap();
+    assert_eq!(matrix[3], [0.0, 2.0, 0.0, 1.0]);
+    // Rotating +X by 90 degrees about +Y yields -Z, scaled by 2.
+    assert!((matrix[0][2] + 2.0).abs() < 1e-3);
+}
+
+#[cfg(feature = "MSFT_lod")]
+#[test]
+fn test_msft_lod() {
+    let gltf = gltf::Gltf::open("tests/msft_lod.gltf").unwrap();
+    let node = gltf.nodes().next().unwrap();
+    let levels = node.lod_chain();
+    let indices = levels
+        .iter()
+        .map(|level| level.object.index())
+        .collect::<Vec<_>>();
+    assert_eq!(indices, [0, 1, 2]);
+    #[cfg(feature = "extras")]
+    {
+        let coverages = levels
+            .iter()
+            .map(|level| level.screen_coverage)
+            .collect::<Vec<_>>();
+        assert_eq!(coverages, [Some(0.5), Some(0.2), Some(0.01)]);
+    }
+    assert_eq!(gltf.nodes().nth(1).unwrap().lod_chain().len(), 1);
+
+    let material = gltf.materials().next().unwrap();
+    let levels = material.lod_chain();
+    let indices = levels
+        .iter()
+        .map(|level| level.object.index())
+        .collect::<Vec<_>>();
+    assert_eq!(indices, [Some(0), Some(1)]);
+    #[cfg(feature = "extras")]
+    assert!(levels.iter().all(|level| level.screen_coverage.is_none()));
+}
+
+#[cfg(feature = "EXT_lights_image_based")]
+#[test]
+fn test_lights_image_based() {
+    let gltf = gltf::Gltf::open("tests/lights_image_based.gltf").unwrap();
+    assert_eq!(gltf.image_based_lights().unwrap().count(), 1);
+
+    let light = gltf.scenes().next().unwrap().image_based_light().unwrap();
+    assert_eq!(light.index(), 0);
+    assert_eq!(light.intensity(), 2.0);
+    let half_sqrt_2 = std::f32::consts::FRAC_1_SQRT_2;
+    let rotation = light.rotation();
+    assert_eq!((rotation[0], rotation[2]), (0.0, 0.0));
+    assert!((rotation[1] - half_sqrt_2).abs() < 1e-6);
+    assert!((rotation[3] - half_sqrt_2).abs() < 1e-6);
+    assert_eq!(light.irradiance_coefficients().len(), 9);
+    assert_eq!(light.irradiance_coefficients()[0], [0.1, 0.2, 0.3]);
+    assert_eq!(light.specular_image_size(), 256);
+    assert_eq!(light.specular_mip_count(), 2);
+    assert_eq!(light.specular_image(1, 0).unwrap().index(), 5);
+    assert!(light.specular_image(2, 0).is_none());
+
+    let mips = light
+        .specular_images()
+        .map(|cube| {
+            cube.unwrap()
+                .iter()
+                .map(|image| image.index())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(mips, [[0, 1, 2, 3, 4, 5], [5, 4, 3, 2, 1, 0]]);
+
+    // Unvalidated references to missing lights are not resolved.
+    let mut json = gltf.document.into_json();
+    json.extensions.as_mut().unwrap().ext_lights_image_based = None;
+    let document = gltf::Document::from_json_without_validation(json);
+    assert!(document
+        .scenes()
+        .next()
+        .unwrap()
+        .image_based_light()
+        .is_none());
+}
+
+#[cfg(feature = "KHR_xmp_json_ld")]
+#[test]
+fn test_xmp_json_ld() {
+    let gltf = gltf::Gltf::open("tests/xmp_json_ld.gltf").unwrap();
+    assert_eq!(gltf.xmp_packets().unwrap().count(), 2);
+
+    let packet = gltf.asset_xmp_packet().unwrap();
+    assert_eq!(packet.index(), 0);
+    assert_eq!(
+        packet.context().unwrap()["dc"],
+        "http://purl.org/dc/elements/1.1/"
+    );
+    assert_eq!(packet.get("dc:creator").unwrap()["@list"][0], "Alice");
+
+    let scene = gltf.scenes().next().unwrap();
+    assert_eq!(scene.xmp_packet().unwrap().index(), 0);
+    let node = gltf.nodes().next().unwrap();
+    let packet = node.xmp_packet().unwrap();
+    assert_eq!(packet.index(), 1);
+    assert_eq!(packet.json_ld()["dc:description"], "A single node");
+
+    let mut materials = gltf.materials();
+    assert_eq!(materials.next().unwrap().xmp_packet().unwrap().index(), 1);
+    assert!(materials.next().unwrap().xmp_packet().is_none());
+
+    // Unvalidated references to missing packets are not resolved.
+    let mut json = gltf.document.into_json();
+    json.extensions.as_mut().unwrap().khr_xmp_json_ld = None;
+    let document = gltf::Document::from_json_without_validation(json);
+    assert!(document.asset_xmp_packet().is_none());
+    assert!(document.nodes().next().unwrap().xmp_packet().is_none());
+}
+
+#[cfg(all(
+    feature = "EXT_mesh_features",
+    feature = "EXT_structural_metadata",
+    feature = "import",
+    feature = "utils"
+))]
+#[test]
+fn test_structural_metadata() {
+    use gltf::ext_structural_metadata::util::Value;
+
+    let (document, buffers, _) = gltf::import("tests/structural_metadata.gltf").unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&*buffers[buffer.index()]);
+    let primitive = document
+        .meshes()
+        .next()
+        .unwrap()
+        .primitives()
+        .next()
+        .unwrap();
+
+    let mut feature_ids = primitive.feature_ids();
+    assert_eq!(feature_ids.len(), 2);
+    let attribute = feature_ids.next().unwrap();
+    assert_eq!(attribute.label(), Some("buildings"));
+    let ids = attribute
+        .reader(get_buffer_data)
+        .read_feature_ids()
+        .unwrap();
+    assert_eq!(ids.collect::<Vec<_>>(), [0, 0, 1]);
+    let implicit = feature_ids.next().unwrap();
+    let ids = implicit.reader(get_buffer_data).read_feature_ids().unwrap();
+    assert_eq!(ids.collect::<Vec<_>>(), [0, 1, 2]);
+
+    let table = attribute.property_table().unwrap();
+    assert_eq!(table.class_id(), "building");
+    assert_eq!(table.count(), 2);
+    let reader = table.reader(get_buffer_data);
+    let read = |id: &str| reader.read_property(id).unwrap().collect::<Vec<_>>();
+    assert_eq!(
+        read("name"),
+        [Value::String("alpha".into()), Value::String("be".into())]
+    );
+    assert_eq!(read("height"), [Value::Float(4.0), Value::Float(5.0)]);
+    assert_eq!(
+        read("kind"),
+        [
+            Value::Enum {
+                value: 1,
+                name: Some("BUILDING")
+            },
+            Value::Enum {
+                value: 0,
+                name: Some("ROAD")
+            },
+        ]
+    );
+    assert_eq!(
+        read("tags"),
+        [
+            Value::Array(vec![Value::UInt(7), Value::UInt(8)]),
+            Value::Array(vec![Value::UInt(9)]),
+        ]
+    );
+    assert_eq!(read("intensity"), [Value::Float(1.0), Value::Float(0.0)]);
+    assert_eq!(read("flags"), [Value::Boolean(true), Value::Boolean(false)]);
+    assert!(reader.read_property("missing").is_none());
+}
+
+#[cfg(feature = "KHR_materials_variants")]
+#[test]
+fn test_materials_variants() {
+    let gltf = gltf::Gltf::open("tests/materials_variants.gltf").unwrap();
+    let red = gltf.variant_by_name("red").unwrap();
+    let plain = gltf.variant_by_name("plain").unwrap();
+    assert_eq!(red.index(), 0);
+    assert!(gltf.variant_by_name("green").is_none());
+
+    let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+    assert_eq!(primitive.material_for_variant(&red).index(), Some(1));
+    assert_eq!(primitive.material_for_variant(&plain).index(), Some(0));
+
+    let pairs = red
+        .primitive_materials()
+        .into_iter()
+        .map(|(primitive, material)| {
+            (
+                primitive.mesh().index(),
+                primitive.index(),
+                material.index(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(pairs, [(0, 0, Some(1)), (1, 0, Some(2))]);
+    assert!(plain.primitive_materials().is_empty());
+}
+
+#[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+#[test]
+fn test_specular_glossiness_conversion() {
+    use gltf::material::spec_gloss::Textures;
+
+    let approx = |a: &[f32], b: &[f32]| a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-3);
+    let gltf = gltf::Gltf::open("tests/specular_glossiness.gltf").unwrap();
+    let mut materials = gltf.materials();
+
+    let dielectric = materials.next().unwrap();
+    let converted = dielectric
+        .pbr_specular_glossiness()
+        .unwrap()
+        .to_metallic_roughness();
+    assert!(approx(&converted.base_color, &[0.5, 0.5, 0.5, 1.0]));
+    assert_eq!(converted.metallic, 0.0);
+    assert!(approx(&[converted.roughness], &[0.25]));
+
+    let metal = materials.next().unwrap();
+    let converted = metal
+        .pbr_specular_glossiness()
+        .unwrap()
+        .to_metallic_roughness();
+    assert!(approx(&converted.base_color, &[1.0, 0.766, 0.336, 0.5]));
+    assert!(approx(&[converted.metallic], &[1.0]));
+
+    let json = metal
+        .to_metallic_roughness_json(&Textures::default())
+        .unwrap();
+    assert!(json.extensions.is_none());
+    assert!(json.double_sided);
+    assert!(approx(
+        &[json.pbr_metallic_roughness.roughness_factor.0],
+        &[0.1]
+    ));
+    assert!(materials
+        .next()
+        .unwrap()
+        .to_metallic_roughness_json(&Textures::default())
+        .is_none());
+}
+
+#[cfg(all(feature = "KHR_materials_pbrSpecularGlossiness", feature = "import"))]
+#[test]
+fn test_specular_glossiness_bake() {
+    use gltf::image::{Data, Format};
+
+    let gltf = gltf::Gltf::open("tests/specular_glossiness.gltf").unwrap();
+    let spec_gloss = gltf
+        .materials()
+        .next()
+        .unwrap()
+        .pbr_specular_glossiness()
+        .unwrap();
+    let diffuse = Data {
+        pixels: vec![255, 255, 255, 255, 0, 0, 0, 128],
+        format: Format::R8G8B8A8,
+        width: 2,
+        height: 1,
+    };
+    let specular_glossiness = Data {
+        pixels: vec![255, 255, 255, 255],
+        format: Format::R8G8B8A8,
+        width: 1,
+        height: 1,
+    };
+    let baked = spec_gloss
+        .bake_textures(Some(&diffuse), Some(&specular_glossiness))
+        .unwrap();
+    assert_eq!((baked.base_color.width, baked.base_color.height), (2, 1));
+    // Diffuse 0.5 is attenuated by the specular factor of 0.04.
+    assert_eq!(baked.base_color.pixels[..4], [188, 188, 188, 255]);
+    assert_eq!(baked.base_color.pixels[7], 128);
+    assert_eq!(baked.metallic_roughness.pixels[..4], [0, 64, 0, 255]);
+    assert!(spec_gloss.bake_textures(None, None).is_none());
+}
+
+#[cfg(all(
+    feature = "KHR_texture_transform",
+    feature = "import",
+    feature = "utils"
+))]
+#[test]
+fn test_texture_transform() {
+    let (document, buffers, _) = gltf::import("tests/texture_transform.gltf").unwrap();
+    let material = document.materials().next().unwrap();
+    let info = material
+        .pbr_metallic_roughness()
+        .base_color_texture()
+        .unwrap();
+    assert_eq!(info.tex_coord(), 0);
+    assert_eq!(info.effective_tex_coord(), 1);
+
+    let transform = info.texture_transform().unwrap();
+    let matrix = transform.matrix();
+    assert!(matrix[0][0].abs() < 1e-6);
+    assert!((matrix[0][1] + 2.0).abs() < 1e-6);
+    assert!((matrix[1][0] - 1.0).abs() < 1e-6);
+    assert_eq!(matrix[2], [0.5, 0.0, 1.0]);
+
+    let mut primitives = document.meshes().next().unwrap().primitives();
+    let primitive = primitives.next().unwrap();
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+    let tex_coords = reader
+        .read_transformed_tex_coords(info.tex_coord(), info.texture_transform())
+        .unwrap();
+    let expected = [[1.0, -1.0], [1.5, -2.0], [0.5, 0.0]];
+    assert_eq!(tex_coords.len(), expected.len());
+    for (actual, expected) in tex_coords.zip(expected.iter()) {
+        assert!((actual[0] - expected[0]).abs() < 1e-5);
+        assert!((actual[1] - expected[1]).abs() < 1e-5);
+    }
+
+    // Without a transform the referenced set is returned unchanged.
+    let primitive = primitives.next().unwrap();
+    let info = primitive
+        .material()
+        .pbr_metallic_roughness()
+        .base_color_texture()
+        .unwrap();
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+    let tex_coords = reader
+        .read_transformed_tex_coords(info.tex_coord(), info.texture_transform())
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(tex_coords, [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]);
+
+    // Normal textures carry their own transform.
+    let normal = primitive.material().normal_texture().unwrap();
+    let tex_coords = reader
+        .read_transformed_tex_coords(normal.tex_coord(), normal.texture_transform())
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(tex_coords, [[1.0, 0.0], [2.0, 0.0], [1.0, 1.0]]);
+}
+
+#[cfg(feature = "KHR_lights_punctual")]
+#[test]
+fn test_light_instances() {
+    let gltf = gltf::Gltf::open("tests/lights_punctual.gltf").unwrap();
+    let scene = gltf.default_scene().unwrap();
+    let instances = scene.light_instances().collect::<Vec<_>>();
+    assert_eq!(instances.len(), 2);
+
+    let spot = &instances[0];
+    assert_eq!((spot.node().index(), spot.light().index()), (1, 0));
+    let expected = [
+        ([1.0, 2.0, 0.0], [-1.0, 0.0, 0.0]),
+        ([0.0; 3], [0.0, 0.0, -1.0]),
+    ];
+    for (instance, (position, direction)) in instances.iter().zip(expected.iter()) {
+        for i in 0..3 {
+            assert!((instance.position()[i] - position[i]).abs() < 1e-5);
+            assert!((instance.direction()[i] - direction[i]).abs() < 1e-5);
+        }
+    }
+    assert_eq!(instances[1].light().intensity(), 3.0);
+}
+
+#[test]
+fn test_scene_traversal() {
+    let gltf = gltf::Gltf::open("tests/scene_traversal.gltf").unwrap();
+    let scene = gltf.default_scene().unwrap();
+    let visited = scene
+        .traverse()
+        .map(|world_node| {
+            let parent = world_node.parent().map(|parent| parent.index());
+            (world_node.node().index(), parent, world_node.matrix()[3])
+        })
+        .collect::<Vec<_>>();
+    // The hand is shared by the arm and the leg, and only visited once.
+    assert_eq!(
+        visited,
+        [
+            (0, None, [1.0, 0.0, 0.0, 1.0]),
+            (1, Some(0), [1.0, 0.0, 0.0, 1.0]),
+            (3, Some(1), [1.0, 2.0, 0.0, 1.0]),
+            (2, Some(0), [1.0, 0.0, 0.0, 1.0]),
+            (4, None, [0.0, 0.0, 5.0, 1.0]),
+        ]
+    );
+}
+
+#[cfg(feature = "names")]
+#[test]
+fn test_node_hierarchy() {
+    let gltf = gltf::Gltf::open("tests/scene_traversal.gltf").unwrap();
+    let hand = gltf.nodes().nth(3).unwrap();
+    assert_eq!(hand.parent().unwrap().index(), 1);
+    let ancestors = hand
+        .ancestors()
+        .map(|node| node.index())
+        .collect::<Vec<_>>();
+    assert_eq!(ancestors, [1, 0]);
+    assert_eq!(hand.path().as_deref(), Some("Root/Arm/Hand"));
+
+    let index = |path| gltf.node_by_path(path).map(|node| node.index());
+    assert_eq!(index("Root/Leg/Hand"), Some(3));
+    assert_eq!(index("Camera"), Some(4));
+    assert_eq!(index("Root/Hand"), None);
+
+    assert_eq!(gltf.scene_of(&hand).unwrap().index(), 0);
+    assert!(gltf.scene_of(&gltf.nodes().nth(5).unwrap()).is_none());
+}
+
+#[cfg(feature = "names")]
+#[test]
+fn test_names() {
+    use gltf::json::Path;
+
+    let gltf = gltf::Gltf::open("tests/names.gltf").unwrap();
+    assert_eq!(gltf.material_by_name("Blue").unwrap().index(), Some(1));
+    assert_eq!(gltf.material_by_name("Red").unwrap().index(), Some(0));
+    assert_eq!(gltf.node_by_name("Red").unwrap().index(), 0);
+    assert_eq!(gltf.scene_by_name("Main").unwrap().index(), 0);
+    assert!(gltf.mesh_by_name("Red").is_none());
+    assert!(gltf.animation_by_name("Red").is_none());
+
+    let path = |s: &str| Path(s.to_owned());
+    assert_eq!(
+        gltf.duplicate_names(),
+        [
+            ("Red", vec![path("materials[0]"), path("materials[2]")]),
+            ("Lamp", vec![path("nodes[1]"), path("nodes[2]")]),
+        ]
+    );
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_bounds() {
+    let (document, buffers, _) = gltf::import("tests/bounds.gltf").unwrap();
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    assert_eq!(primitive.bounding_box().max, [1.0, 1.0, 0.0]);
+    assert_eq!(primitive.morphed_bounding_box().max, [1.0, 1.0, 1.0]);
+
+    let node = document.nodes().next().unwrap();
+    let bounds = node.bounding_box().unwrap();
+    assert_eq!(
+        (bounds.min, bounds.max),
+        ([10.0, 0.0, 0.0], [11.0, 1.0, 1.0])
+    );
+
+    let scene = document.default_scene().unwrap();
+    let bounds = scene.bounding_box().unwrap();
+    assert_eq!((bounds.min, bounds.max), ([0.0; 3], [101.0, 7.0, 2.0]));
+
+    // Morph weights are applied and the skinned mesh follows its joint,
+    // except for its last vertex which has no weights and stays with the node.
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&*buffers[buffer.index()]);
+    let bounds = scene.tight_bounding_box(get_buffer_data).unwrap();
+    assert_eq!(
+        (bounds.min, bounds.max),
+        ([0.0, 0.0, -4.0], [100.0, 7.0, 0.5])
+    );
+
+    let sphere = bounds.bounding_sphere();
+    assert_eq!(sphere.center, [50.0, 3.5, -1.75]);
+    assert!((sphere.radius - (50.0f32 * 50.0 + 3.5 * 3.5 + 2.25 * 2.25).sqrt()).abs() < 1e-4);
+}
+
+#[test]
+fn test_camera_matrices() {
+    use gltf::camera::ProjectionOptions;
+
+    fn transform(m: &[[f32; 4]; 4], v: [f32; 4]) -> [f32; 4] {
+        let mut out = [0.0; 4];
+        for (column, x) in m.iter().zip(v.iter()) {
+            for i in 0..4 {
+                out[i] += column[i] * x;
+            }
+        }
+        out
+    }
+    // Returns the normalized device depth of a point on the view axis.
+    fn depth(m: &[[f32; 4]; 4], distance: f32) -> f32 {
+        let clip = transform(m, [0.0, 0.0, -distance, 1.0]);
+        clip[2] / clip[3]
+    }
+    let close = |a: f32, b: f32| (a - b).abs() < 1e-5;
+
+    let gltf = gltf::Gltf::open("tests/cameras.gltf").unwrap();
+    let mut cameras = gltf.cameras();
+    let finite = cameras.next().unwrap();
+    let m = finite.projection_matrix(ProjectionOptions::default());
+    assert!(close(m[1][1], 1.0 / 0.5f32.tan()));
+    assert!(close(m[0][0], m[1][1] / 2.0));
+    assert!(close(depth(&m, 1.0), -1.0) && close(depth(&m, 3.0), 1.0));
+    let options = ProjectionOptions {
+        aspect_ratio: Some(1.0),
+        reversed_z: true,
+    };
+    let m = finite.projection_matrix(options);
+    assert!(close(m[0][0], m[1][1]));
+    assert!(close(depth(&m, 1.0), 1.0) && close(depth(&m, 3.0), 0.0));
+
+    let infinite = cameras.next().unwrap();
+    let m = infinite.projection_matrix(ProjectionOptions::default());
+    assert!(close(m[0][0], 1.0) && close(depth(&m, 0.5), -1.0));
+    assert!(depth(&m, 1e6) < 1.0 && depth(&m, 1e6) > 0.999);
+    let m = infinite.projection_matrix(options);
+    assert!(close(depth(&m, 0.5), 1.0) && depth(&m, 1e6) < 1e-5);
+
+    let orthographic = cameras.next().unwrap();
+    let m = orthographic.projection_matrix(ProjectionOptions::default());
+    assert!(close(m[0][0], 0.5) && close(m[1][1], 1.0));
+    assert!(close(depth(&m, 1.0), -1.0) && close(depth(&m, 3.0), 1.0));
+    let m = orthographic.projection_matrix(ProjectionOptions {
+        aspect_ratio: Some(4.0),
+        reversed_z: true,
+    });
+    assert!(close(m[0][0], 0.25));
+    assert!(close(depth(&m, 1.0), 1.0) && close(depth(&m, 3.0), 0.0));
+
+    let mut nodes = gltf.nodes();
+    let node = nodes.next().unwrap();
+    let view = node.view_matrix().unwrap();
+    let origin = transform(&view, [1.0, 2.0, 3.0, 1.0]);
+    assert!(origin
+        .iter()
+        .zip([0.0, 0.0, 0.0, 1.0])
+        .all(|(a, b)| close(*a, b)));
+    // The camera looks down its local -Z axis, rotated onto world -X.
+    let ahead = transform(&view, [-1.0, 2.0, 3.0, 1.0]);
+    assert!(close(ahead[2], -1.0));
+    assert!(nodes.next().unwrap().view_matrix().is_none());
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_animation_sampling() {
+    use gltf::animation::util::sampling::Value;
+
+    let (document, buffers, _) = gltf::import("tests/animation_sampling.gltf").unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&*buffers[buffer.index()]);
+    let close = |a: &[f32], b: &[f32]| a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-4);
+    let animation = document.animations().next().unwrap();
+    let mut channels = animation.channels();
+
+    let translation = channels.next().unwrap().reader(get_buffer_data);
+    assert_eq!(translation.sample(-1.0), Some(Value::Translation([0.0; 3])));
+    assert_eq!(
+        translation.sample(0.5),
+        Some(Value::Translation([1.0, 0.0, 0.0]))
+    );
+    assert_eq!(
+        translation.sample(1.5),
+        Some(Value::Translation([2.0, 2.0, 0.0]))
+    );
+    assert_eq!(
+        translation.sample(9.0),
+        Some(Value::Translation([2.0, 4.0, 0.0]))
+    );
+
+    // Quantized rotations are normalized and interpolated along the arc.
+    let rotation = channels.next().unwrap().reader(get_buffer_data);
+    let keyframes = rotation.read_keyframes().unwrap();
+    assert_eq!(keyframes.width(), 4);
+    assert_eq!(keyframes.sample(0.0), Value::Rotation([0.0, 0.0, 0.0, 1.0]));
+    match keyframes.sample(0.5) {
+        Value::Rotation(q) => {
+            let (sin, cos) = std::f32::consts::FRAC_PI_8.sin_cos();
+            assert!(close(&q, &[0.0, sin, 0.0, cos]));
+        }
+        value => panic!("unexpected value {:?}", value),
+    }
+
+    let scale = channels.next().unwrap().reader(get_buffer_data);
+    assert_eq!(scale.sample(0.99), Some(Value::Scale([1.0; 3])));
+    assert_eq!(scale.sample(1.0), Some(Value::Scale([2.0; 3])));
+
+    // The out-tangent of the first keyframe overshoots the linear midpoint.
+    let weights = channels.next().unwrap().reader(get_buffer_data);
+    assert_eq!(
+        weights.sample(0.5),
+        Some(Value::MorphTargetWeights(vec![0.625]))
+    );
+    assert_eq!(
+        weights.sample(1.0),
+        Some(Value::MorphTargetWeights(vec![1.0]))
+    );
+
+    // Three timestamps with only two translations are rejected, as are
+    // repeated timestamps.
+    let invalid = document.animations().nth(1).unwrap();
+    for channel in invalid.channels() {
+        assert!(channel.reader(get_buffer_data).read_keyframes().is_none());
+    }
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_animation_pose() {
+    let (document, buffers, _) = gltf::import("tests/animation_sampling.gltf").unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&*buffers[buffer.index()]);
+    let animation = document.animations().next().unwrap();
+    assert_eq!(animation.duration(), 2.0);
+
+    let pose = animation.sample(0.5, get_buffer_data);
+    assert_eq!(pose.nodes().len(), 2);
+    let animated = pose.get(0).unwrap();
+    assert_eq!(animated.translation, [1.0, 0.0, 0.0]);
+    assert_eq!(animated.scale, [1.0; 3]);
+    assert_eq!(animated.weights, Some(vec![0.625]));
+
+    // Properties without a channel keep the rest values of the node.
+    let rest = pose.get(1).unwrap();
+    assert_eq!(rest.node().index(), 1);
+    assert_eq!(rest.translation, [1.0, 0.0, 0.0]);
+    assert_eq!(rest.rotation, [0.0, 0.0, 1.0, 0.0]);
+    assert_eq!(rest.scale, [5.0; 3]);
+    assert_eq!(rest.weights, None);
+    assert!(pose.get(2).is_none());
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_joint_matrices() {
+    let (document, buffers, _) = gltf::import("tests/skin.gltf").unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&*buffers[buffer.index()]);
+    let translation = |matrix: &[[f32; 4]; 4]| [matrix[3][0], matrix[3][1], matrix[3][2]];
+    let mesh_node = document.nodes().next().unwrap();
+    let mut skins = document.skins();
+
+    // In the rest pose the joints cancel their inverse bind matrices, leaving
+    // the inverse transform of the mesh node.
+    let skin = skins.next().unwrap();
+    let rest = skin
+        .joint_matrices(&mesh_node, |node| node.world_matrix(), get_buffer_data)
+        .unwrap();
+    assert_eq!(rest.len(), 2);
+    assert!(rest.iter().all(|m| translation(m) == [0.0, 0.0, -5.0]));
+
+    let pose = document
+        .animations()
+        .next()
+        .unwrap()
+        .sample(0.0, get_buffer_data);
+    let world_matrices = pose.world_matrices();
+    assert_eq!(translation(&world_matrices[2]), [3.0, 2.0, 0.0]);
+    let posed = skin
+        .joint_matrices(
+            &mesh_node,
+            |node| world_matrices[node.index()],
+            get_buffer_data,
+        )
+        .unwrap();
+    assert!(posed.iter().all(|m| translation(m) == [2.0, 0.0, -5.0]));
+
+    // Missing inverse bind matrices are treated as the identity.
+    let skin = skins.next().unwrap();
+    let rest = skin
+        .joint_matrices(&mesh_node, |node| node.world_matrix(), get_buffer_data)
+        .unwrap();
+    assert_eq!(translation(&rest[0]), [1.0, 2.0, -5.0]);
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_deform() {
+    let (document, buffers, _) = gltf::import("tests/deform.gltf").unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&*buffers[buffer.index()]);
+    let close = |a: &[f32], b: &[f32]| a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-5);
+    let primitive = document
+        .meshes()
+        .next()
+        .unwrap()
+        .primitives()
+        .next()
+        .unwrap();
+    let reader = primitive.reader(get_buffer_data);
+
+    let rest = reader.read_deformed(&[], None).unwrap();
+    assert_eq!(rest.positions, [[0.0; 3], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+    assert_eq!(rest.normals, Some(vec![[0.0, 0.0, 1.0]; 3]));
+
+    // Morph targets are applied before skinning with both joint sets.
+    let identity = [
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ];
+    let mut translation = identity;
+    translation[3] = [2.0, 0.0, 0.0, 1.0];
+    let deformed = reader
+        .read_deformed(&[1.0], Some(&[identity, translation]))
+        .unwrap();
+    assert_eq!(
+        deformed.positions,
+        [[0.0, 0.0, 1.0], [2.0, 0.0, 1.0], [1.0, 1.0, 1.0]]
+    );
+    let normals = deformed.normals.unwrap();
+    let half_sqrt_2 = std::f32::consts::FRAC_1_SQRT_2;
+    assert!(close(&normals[0], &[half_sqrt_2, 0.0, half_sqrt_2]));
+
+    // Normals follow the inverse transpose of non-uniform scales.
+    let mut scale = identity;
+    scale[0][0] = 2.0;
+    let deformed = reader.read_deformed(&[1.0], Some(&[scale])).unwrap();
+    assert_eq!(deformed.positions[0], [0.0, 0.0, 1.0]);
+    let normal = deformed.normals.unwrap()[0];
+    let length = 1.25f32.sqrt();
+    assert!(close(&normal, &[0.5 / length, 0.0, 1.0 / length]));
+    assert_eq!(deformed.tangents.unwrap()[0], [1.0, 0.0, 0.0, 1.0]);
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_keyframe_reduction() {
+    use gltf::animation::util::sampling::Value;
+
+    let (document, buffers, _) = gltf::import("tests/animation_sampling.gltf").unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&*buffers[buffer.index()]);
+    let animation = document.animations().next().unwrap();
+    let mut channels = animation.channels();
+
+    let translation = channels.next().unwrap().reader(get_buffer_data);
+    let keyframes = translation.read_keyframes().unwrap();
+    let resampled = keyframes.resample(2.0);
+    assert_eq!(resampled.inputs(), [0.0, 0.5, 1.0, 1.5, 2.0]);
+    assert_eq!(resampled.sample(1.5), keyframes.sample(1.5));
+    let reduced = resampled.reduce(1e-4);
+    assert_eq!(reduced.inputs(), [0.0, 1.0, 2.0]);
+    assert_eq!(reduced.outputs(), keyframes.outputs());
+
+    // The middle rotation lies on the arc between its neighbours.
+    let rotation = channels.next().unwrap().reader(get_buffer_data);
+    let reduced = rotation.read_keyframes().unwrap().reduce(1e-3);
+    assert_eq!(reduced.inputs(), [0.0, 2.0]);
+
+    // Step keyframes are only removed when they repeat a value.
+    let scale = channels.next().unwrap().reader(get_buffer_data);
+    let keyframes = scale.read_keyframes().unwrap();
+    assert_eq!(keyframes.reduce(0.1).inputs().len(), 3);
+    assert_eq!(keyframes.reduce(2.0).inputs(), [0.0]);
+
+    let (root, data) =
+        gltf::animation::util::rewrite_keyframes(&document, get_buffer_data, 0, |_, keyframes| {
+            keyframes.reduce(1e-3)
+        })
+        .unwrap();
+    assert_eq!(root.buffers.len(), 1);
+    assert_eq!(&data[..140], &buffers[0][..140]);
+    let rewritten = gltf::Document::from_json(root).unwrap();
+    let get_buffer_data = |_| Some(&*data);
+    let animation = rewritten.animations().next().unwrap();
+    let channels = animation.channels().collect::<Vec<_>>();
+    let readers = channels
+        .iter()
+        .map(|channel| channel.reader(get_buffer_data))
+        .collect::<Vec<_>>();
+    assert_eq!(readers[1].read_keyframes().unwrap().inputs(), [0.0, 2.0]);
+    assert_eq!(
+        readers[4].sample(0.5),
+        Some(Value::Translation([1.0, 0.0, 0.0]))
+    );
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_linear_conversion() {
+    use gltf::animation::util::sampling::Value;
+    use gltf::animation::Interpolation;
+
+    let (document, buffers, _) = gltf::import("tests/animation_sampling.gltf").unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&*buffers[buffer.index()]);
+    let animation = document.animations().next().unwrap();
+    let channels = animation.channels().collect::<Vec<_>>();
+
+    // Steps are reproduced by holding the previous value until the key.
+    let step = channels[2]
+        .reader(get_buffer_data)
+        .read_keyframes()
+        .unwrap();
+    let linear = step.to_linear(1e-3);
+    assert_eq!(linear.interpolation(), Interpolation::Linear);
+    assert_eq!(linear.inputs().len(), 5);
+    for time in [0.5, 0.9999, 1.0, 1.5, 2.0].iter() {
+        assert_eq!(linear.sample(*time), step.sample(*time));
+    }
+
+    // Splines are subdivided until they are within tolerance.
+    let spline = channels[3]
+        .reader(get_buffer_data)
+        .read_keyframes()
+        .unwrap();
+    let linear = spline.to_linear(1e-3);
+    assert!(linear.inputs().len() > 2);
+    for i in 0..=100 {
+        let time = i as f32 / 100.0;
+        let (a, b) = match (linear.sample(time), spline.sample(time)) {
+            (Value::MorphTargetWeights(a), Value::MorphTargetWeights(b)) => (a, b),
+            _ => unreachable!(),
+        };
+        assert!((a[0] - b[0]).abs() <= 1e-3);
+    }
+
+    let buffer = document.buffers().len();
+    let (root, _) = gltf::animation::util::rewrite_keyframes(
+        &document,
+        get_buffer_data,
+        buffer,
+        |_, keyframes| keyframes.to_linear(1e-3),
+    )
+    .unwrap();
+    assert_eq!(root.buffers.len(), buffer + 1);
+    assert!(gltf::animation::util::rewrite_keyframes(
+        &document,
+        get_buffer_data,
+        buffer + 1,
+        |_, k| k
+    )
+    .is_none());
+    let rewritten = gltf::Document::from_json(root).unwrap();
+    let animation = rewritten.animations().next().unwrap();
+    assert!(animation
+        .samplers()
+        .all(|sampler| sampler.interpolation() == Interpolation::Linear));
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_topology_conversion() {
+    let (document, buffers, _) = gltf::import("tests/topology.gltf").unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&*buffers[buffer.index()]);
+    let mesh = document.meshes().next().unwrap();
+    let readers = mesh
+        .primitives()
+        .map(|primitive| primitive.reader(get_buffer_data))
+        .collect::<Vec<_>>();
+
+    let quad = readers[0].read_triangles().unwrap();
+    assert_eq!(quad, [[0, 1, 2], [2, 1, 3]]);
+    assert_eq!(
+        readers[0].read_wireframe().unwrap(),
+        [[0, 1], [1, 2], [2, 0], [1, 3], [3, 2]]
+    );
+    assert!(readers[0].read_lines().is_none());
+
+    // Every other triangle of a strip is flipped to keep the winding.
+    assert_eq!(
+        readers[1].read_triangles().unwrap(),
+        [[0, 1, 2], [1, 3, 2], [2, 3, 4]]
+    );
+    assert_eq!(readers[2].read_triangles().unwrap(), [[0, 1, 4], [1, 3, 4]]);
+
+    assert_eq!(
+        readers[3].read_lines().unwrap(),
+        [[0, 1], [1, 2], [2, 3], [3, 4], [4, 0]]
+    );
+    assert_eq!(readers[4].read_lines().unwrap().len(), 4);
+    assert!(readers[4].read_triangles().is_none());
+    assert!(readers[5].read_lines().is_none());
+    assert!(readers[5].read_wireframe().is_none());
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_normal_generation() {
+    let (document, buffers, _) = gltf::import("tests/generate.gltf").unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&*buffers[buffer.index()]);
+    let close = |a: &[f32], b: &[f32]| a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-5);
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(get_buffer_data);
+
+    let flat = reader.generate_flat_normals().unwrap().collect::<Vec<_>>();
+    assert_eq!(
+        flat,
+        [[0.0, 0.0, 1.0]; 3]
+            .iter()
+            .chain(&[[1.0, 0.0, 0.0]; 3])
+            .copied()
+            .collect::<Vec<_>>()
+    );
+
+    // Vertices on the fold average the normals of both triangles.
+    let smooth = reader
+        .generate_smooth_normals()
+        .unwrap()
+        .collect::<Vec<_>>();
+    let half_sqrt_2 = std::f32::consts::FRAC_1_SQRT_2;
+    assert!(close(&smooth[0], &[half_sqrt_2, 0.0, half_sqrt_2]));
+    assert!(close(&smooth[1], &[half_sqrt_2, 0.0, half_sqrt_2]));
+    assert_eq!(smooth[2], [0.0, 0.0, 1.0]);
+    assert_eq!(smooth[3], [1.0, 0.0, 0.0]);
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_extract() {
+    let (document, buffers, _) = gltf::import("tests/extract.gltf").unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&*buffers[buffer.index()]);
+    let primitive = document
+        .meshes()
+        .next()
+        .unwrap()
+        .primitives()
+        .next()
+        .unwrap();
+    let data = primitive.extract(get_buffer_data).unwrap();
+
+    assert_eq!(data.mode, gltf::mesh::Mode::Triangles);
+    assert_eq!(data.material, Some(0));
+    assert_eq!(data.indices, Some(vec![0, 1, 2]));
+    assert_eq!(data.positions[1], [1.0, 0.0, 0.0]);
+    assert_eq!(data.normals, None);
+    assert_eq!(data.tex_coords, [vec![[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]]);
+    assert_eq!(data.colors[0][0], [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(data.joints, [vec![[0, 1, 0, 0]; 3]]);
+    assert_eq!(data.weights, [vec![[0.75, 0.25, 0.0, 0.0]; 3]]);
+    assert_eq!(data.morph_targets.len(), 1);
+    assert_eq!(
+        data.morph_targets[0].positions,
+        Some(vec![[0.0, 0.0, 1.0]; 3])
+    );
+    assert_eq!(data.morph_targets[0].normals, None);
+
+    let interleaved = data.interleave().unwrap();
+    let offsets = interleaved
+        .attributes
+        .iter()
+        .map(|attribute| (attribute.semantic.clone(), attribute.offset))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        offsets,
+        [
+            (gltf::Semantic::Positions, 0),
+            (gltf::Semantic::TexCoords(0), 12),
+            (gltf::Semantic::Colors(0), 20),
+            (gltf::Semantic::Joints(0), 36),
+            (gltf::Semantic::Weights(0), 44),
+        ]
+    );
+    assert_eq!(interleaved.stride, 60);
+    assert_eq!(interleaved.data.len(), 180);
+    assert_eq!(interleaved.data[60..64], 1.0f32.to_le_bytes());
+    assert_eq!(interleaved.data[96..100], [0, 0, 1, 0]);
+
+    // Attributes shorter than the positions cannot be interleaved.
+    let mut short = data.clone();
+    short.colors[0].pop();
+    assert!(short.interleave().is_none());
+}
+
+#[cfg(all(feature = "extras", feature = "import", feature = "utils"))]
+#[test]
+fn test_extract_custom_attributes() {
+    use gltf::mesh::util::extract::CustomData;
+
+    let (document, buffers, _) = gltf::import("tests/extract_custom.gltf").unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&*buffers[buffer.index()]);
+    let primitive = document
+        .meshes()
+        .next()
+        .unwrap()
+        .primitives()
+        .next()
+        .unwrap();
+    let data = primitive.extract(get_buffer_data).unwrap();
+
+    assert_eq!(data.custom.len(), 1);
+    let (name, attribute) = &data.custom[0];
+    assert_eq!(name, "ID");
+    assert_eq!(attribute.dimensions, gltf::accessor::Dimensions::Vec2);
+    assert!(!attribute.normalized);
+    assert_eq!(attribute.data, CustomData::U16(vec![1, 2, 3, 4, 5, 6]));
+
+    // Custom attributes are left out of the interleaved layout.
+    assert_eq!(data.interleave().unwrap().stride, 60);
+}